@@ -0,0 +1,91 @@
+-- The tables of USDC_WETH_POOL indexed by its event types, generated by `generate`.
+-- They require the `ethereum_logs` and `addresses` tables of the indexer.
+
+CREATE TABLE IF NOT EXISTS swap_logs (
+    id SERIAL PRIMARY KEY,
+    ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
+    block_number BYTEA,
+    sender_id INT NOT NULL REFERENCES addresses (id),
+    receiver_id INT NOT NULL REFERENCES addresses (id),
+    amount0 BYTEA NOT NULL,
+    amount1 BYTEA NOT NULL,
+    sqrt_price_x96 BYTEA NOT NULL,
+    liquidity BYTEA NOT NULL,
+    tick INT NOT NULL,
+    timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS swap_logs_ethereum_log_id_idx ON swap_logs (ethereum_log_id);
+CREATE INDEX IF NOT EXISTS swap_logs_sender_idx ON swap_logs (sender_id);
+CREATE INDEX IF NOT EXISTS swap_logs_receiver_idx ON swap_logs (receiver_id);
+
+CREATE OR REPLACE VIEW swap_events AS
+SELECT e.*, a_sender.address AS sender_address, a_receiver.address AS receiver_address
+FROM swap_logs e JOIN addresses a_sender ON a_sender.id = e.sender_id JOIN addresses a_receiver ON a_receiver.id = e.receiver_id;
+
+CREATE TABLE IF NOT EXISTS burn_logs (
+    id SERIAL PRIMARY KEY,
+    ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
+    block_number BYTEA,
+    owner_id INT NOT NULL REFERENCES addresses (id),
+    tick_lower INT NOT NULL,
+    tick_upper INT NOT NULL,
+    amount BYTEA NOT NULL,
+    amount0 BYTEA NOT NULL,
+    amount1 BYTEA NOT NULL,
+    timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS burn_logs_ethereum_log_id_idx ON burn_logs (ethereum_log_id);
+CREATE INDEX IF NOT EXISTS burn_logs_owner_idx ON burn_logs (owner_id);
+CREATE INDEX IF NOT EXISTS burn_logs_tick_lower_idx ON burn_logs (tick_lower);
+CREATE INDEX IF NOT EXISTS burn_logs_tick_upper_idx ON burn_logs (tick_upper);
+
+CREATE OR REPLACE VIEW burn_events AS
+SELECT e.*, a_owner.address AS owner_address
+FROM burn_logs e JOIN addresses a_owner ON a_owner.id = e.owner_id;
+
+CREATE TABLE IF NOT EXISTS mint_logs (
+    id SERIAL PRIMARY KEY,
+    ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
+    block_number BYTEA,
+    sender_id INT NOT NULL REFERENCES addresses (id),
+    owner_id INT NOT NULL REFERENCES addresses (id),
+    tick_lower INT NOT NULL,
+    tick_upper INT NOT NULL,
+    amount BYTEA NOT NULL,
+    amount0 BYTEA NOT NULL,
+    amount1 BYTEA NOT NULL,
+    timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS mint_logs_ethereum_log_id_idx ON mint_logs (ethereum_log_id);
+CREATE INDEX IF NOT EXISTS mint_logs_owner_idx ON mint_logs (owner_id);
+CREATE INDEX IF NOT EXISTS mint_logs_tick_lower_idx ON mint_logs (tick_lower);
+CREATE INDEX IF NOT EXISTS mint_logs_tick_upper_idx ON mint_logs (tick_upper);
+
+CREATE OR REPLACE VIEW mint_events AS
+SELECT e.*, a_sender.address AS sender_address, a_owner.address AS owner_address
+FROM mint_logs e JOIN addresses a_sender ON a_sender.id = e.sender_id JOIN addresses a_owner ON a_owner.id = e.owner_id;
+
+CREATE TABLE IF NOT EXISTS flash_logs (
+    id SERIAL PRIMARY KEY,
+    ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
+    block_number BYTEA,
+    sender_id INT NOT NULL REFERENCES addresses (id),
+    receiver_id INT NOT NULL REFERENCES addresses (id),
+    amount0 BYTEA NOT NULL,
+    amount1 BYTEA NOT NULL,
+    paid0 BYTEA NOT NULL,
+    paid1 BYTEA NOT NULL,
+    timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS flash_logs_ethereum_log_id_idx ON flash_logs (ethereum_log_id);
+CREATE INDEX IF NOT EXISTS flash_logs_sender_idx ON flash_logs (sender_id);
+CREATE INDEX IF NOT EXISTS flash_logs_receiver_idx ON flash_logs (receiver_id);
+
+CREATE OR REPLACE VIEW flash_events AS
+SELECT e.*, a_sender.address AS sender_address, a_receiver.address AS receiver_address
+FROM flash_logs e JOIN addresses a_sender ON a_sender.id = e.sender_id JOIN addresses a_receiver ON a_receiver.id = e.receiver_id;
+
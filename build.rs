@@ -0,0 +1,28 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[allow(dead_code)]
+#[path = "src/generate.rs"]
+mod generate;
+
+/// Generates the bindings of every ABI under `abi/` into `OUT_DIR`, with a `bindings.rs`
+/// declaring a module per contract for `types::bindings`.
+fn main() -> eyre::Result<()> {
+    println!("cargo:rerun-if-changed=abi");
+    println!("cargo:rerun-if-changed=src/generate.rs");
+
+    let out = PathBuf::from(env::var("OUT_DIR")?);
+    let mut modules = String::new();
+
+    for (_, module, source) in generate::module_bindings(Path::new("abi"))? {
+        fs::write(out.join(format!("{module}.rs")), source)?;
+        modules += &format!(
+            "#[allow(clippy::module_inception)]\npub mod {module} {{\n    include!(concat!(env!(\"OUT_DIR\"), \"/{module}.rs\"));\n}}\n"
+        );
+    }
+
+    fs::write(out.join("bindings.rs"), modules)?;
+
+    Ok(())
+}
@@ -0,0 +1,167 @@
+//! `#[derive(IndexedEvent)]` for the Abigen event structs stored by `eth_logs`.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr, Result};
+
+/// Implements `eth_logs::indexed::IndexedEvent` and `TryFrom<&tokio_postgres::Row>` for an
+/// event struct with named fields.
+///
+/// The table is the snake case name of the struct without its `Filter` suffix, followed by
+/// `_logs`, e.g. `swap_logs` for `SwapFilter`, unless set with
+/// `#[indexed_event(table = "...")]`. Each field is a column named after it, with the
+/// underscore before a trailing number removed, e.g. `amount0` for `amount_0`, unless set with
+/// `#[indexed_event(column = "...")]`.
+///
+/// Each field is stored in the column `eth_logs::indexed::ColumnKind::of` maps its Solidity type
+/// to, through the `eth_logs::indexed::Stored` implementation of its Rust type. Fields Abigen
+/// marks `#[ethevent(indexed)]`, the inputs indexed in the topics, get an index on their column.
+///
+/// The generated code names `::eth_logs` and `::tokio_postgres`, which the crate deriving it
+/// must depend on.
+#[proc_macro_derive(IndexedEvent, attributes(indexed_event))]
+pub fn derive_indexed_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "IndexedEvent can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            input,
+            "IndexedEvent requires named fields",
+        ));
+    };
+
+    let table = match attribute(&input.attrs, "table")? {
+        Some(table) => table,
+        None => default_table(&name.to_string()),
+    };
+
+    let mut columns = Vec::new();
+    let mut values = Vec::new();
+    let mut reads = Vec::new();
+
+    for field in &fields.named {
+        let ident = field.ident.as_ref().expect("named field");
+        let column = match attribute(&field.attrs, "column")? {
+            Some(column) => column,
+            None => default_column(&ident.to_string()),
+        };
+        let ty = &field.ty;
+        let indexed = indexed(&field.attrs)?;
+
+        columns.push(quote! {
+            ::eth_logs::indexed::Column {
+                name: #column,
+                kind: <#ty as ::eth_logs::indexed::Stored>::KIND,
+                indexed: #indexed,
+            }
+        });
+        values.push(quote!(::eth_logs::indexed::Stored::value(&self.#ident)));
+        reads.push(quote!(#ident: <#ty as ::eth_logs::indexed::Stored>::read(row, #column)?));
+    }
+
+    Ok(quote! {
+        impl ::eth_logs::indexed::IndexedEvent for #name {
+            const SCHEMA: ::eth_logs::indexed::Schema = ::eth_logs::indexed::Schema {
+                table: #table,
+                columns: &[#(#columns),*],
+            };
+
+            fn values(&self) -> Vec<::eth_logs::indexed::Value> {
+                vec![#(#values),*]
+            }
+
+            fn from_row(
+                row: &::tokio_postgres::Row,
+            ) -> ::std::result::Result<Self, ::eth_logs::error::IndexerError> {
+                Ok(Self {
+                    #(#reads),*
+                })
+            }
+        }
+
+        impl TryFrom<&::tokio_postgres::Row> for #name {
+            type Error = ::eth_logs::error::IndexerError;
+
+            fn try_from(row: &::tokio_postgres::Row) -> ::std::result::Result<Self, Self::Error> {
+                <Self as ::eth_logs::indexed::IndexedEvent>::from_row(row)
+            }
+        }
+    })
+}
+
+/// Reads `#[indexed_event(key = "value")]` from a list of attributes.
+fn attribute(attrs: &[syn::Attribute], key: &str) -> Result<Option<LitStr>> {
+    let mut value = None;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("indexed_event")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                value = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error(format!("expected `{key}`")))
+            }
+        })?;
+    }
+
+    Ok(value)
+}
+
+/// Whether a field is marked `#[ethevent(indexed)]`.
+fn indexed(attrs: &[syn::Attribute]) -> Result<bool> {
+    let mut indexed = false;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("ethevent")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("indexed") {
+                indexed = true;
+            } else if let Ok(value) = meta.value() {
+                // Skip the value of other keys, such as `name = "..."`
+                value.parse::<syn::Expr>()?;
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(indexed)
+}
+
+/// `SwapFilter` to `swap_logs`.
+fn default_table(name: &str) -> LitStr {
+    let name = name.strip_suffix("Filter").unwrap_or(name);
+
+    let mut table = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            table.push('_');
+        }
+        table.push(c.to_ascii_lowercase());
+    }
+
+    LitStr::new(&format!("{table}_logs"), Span::call_site())
+}
+
+/// `amount_0` to `amount0`.
+fn default_column(field: &str) -> LitStr {
+    let column = match field.rsplit_once('_') {
+        Some((head, tail)) if tail.chars().all(|c| c.is_ascii_digit()) => format!("{head}{tail}"),
+        _ => field.to_string(),
+    };
+
+    LitStr::new(&column, Span::call_site())
+}
@@ -0,0 +1,195 @@
+use ethers::types::H160;
+use eyre::{eyre, Result};
+use lru::LruCache;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+
+use crate::database::DB;
+use crate::indexed::SCHEMAS;
+use crate::types::Address;
+
+/// The number of address ids kept in memory.
+const CACHE_SIZE: usize = 100_000;
+
+/// The ids of the `addresses` table known to a connection.
+///
+/// Ids read or inserted inside a transaction are only visible to its connection until it
+/// commits, and disappear if it rolls back, so they stay pending and only reach the committed
+/// ids on commit.
+#[derive(Debug)]
+pub struct AddressCache {
+    committed: LruCache<H160, i32>,
+    pending: HashMap<H160, i32>,
+}
+
+impl AddressCache {
+    /// Creates an empty cache keeping up to `capacity` committed ids.
+    ///
+    /// # Arguments
+    ///
+    /// - `capacity`: The maximum number of committed ids.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            committed: LruCache::new(capacity),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Returns the id of an address, pending or committed.
+    pub fn get(&mut self, address: &H160) -> Option<i32> {
+        self.pending
+            .get(address)
+            .copied()
+            .or_else(|| self.committed.get(address).copied())
+    }
+
+    /// Keeps the id of an address read or inserted by the open transaction.
+    pub fn insert(&mut self, address: H160, id: i32) {
+        self.pending.insert(address, id);
+    }
+
+    /// Moves the pending ids into the committed ones, once their transaction commits.
+    pub fn commit(&mut self) {
+        for (address, id) in self.pending.drain() {
+            self.committed.put(address, id);
+        }
+    }
+
+    /// Forgets the pending ids of a rolled back transaction or savepoint.
+    pub fn forget(&mut self) {
+        self.pending.clear();
+    }
+}
+
+impl Default for AddressCache {
+    fn default() -> Self {
+        Self::new(NonZeroUsize::new(CACHE_SIZE).unwrap())
+    }
+}
+
+/// Returns the statements moving the address columns of older event tables into the
+/// `addresses` table, then creating the views that present the event tables with their
+/// addresses.
+pub fn address_schema() -> String {
+    let mut sql = String::new();
+
+    // Each table stores the id of an address in `<role>_id`, and its view exposes the address
+    // as `<role>_address`
+    for schema in SCHEMAS {
+        let table = schema.table;
+        let roles: Vec<&str> = schema.address_columns().collect();
+
+        for role in &roles {
+            sql += &format!(
+                "
+            DO $$
+            BEGIN
+                IF EXISTS (
+                    SELECT 1 FROM information_schema.columns
+                    WHERE table_name = '{table}' AND column_name = '{role}_address'
+                ) THEN
+                    INSERT INTO addresses (address)
+                    SELECT DISTINCT {role}_address FROM {table}
+                    ON CONFLICT (address) DO NOTHING;
+
+                    ALTER TABLE {table} ADD COLUMN {role}_id INT REFERENCES addresses (id);
+                    UPDATE {table} e SET {role}_id = a.id
+                    FROM addresses a WHERE a.address = e.{role}_address;
+                    ALTER TABLE {table} ALTER COLUMN {role}_id SET NOT NULL;
+                    ALTER TABLE {table} DROP COLUMN {role}_address;
+                END IF;
+            END $$;
+            "
+            );
+        }
+
+        sql += &address_view(table, &schema.view(), &roles);
+    }
+
+    sql
+}
+
+/// Returns the statement creating the view that presents an event table with its addresses.
+///
+/// # Arguments
+///
+/// - `table`: The event table.
+/// - `view`: The name of the view.
+/// - `roles`: The roles of the address columns of the table.
+pub(crate) fn address_view(table: &str, view: &str, roles: &[&str]) -> String {
+    let columns: String = roles
+        .iter()
+        .map(|role| format!(", a_{role}.address AS {role}_address"))
+        .collect();
+    let joins: String = roles
+        .iter()
+        .map(|role| format!(" JOIN addresses a_{role} ON a_{role}.id = e.{role}_id"))
+        .collect();
+
+    format!(
+        "
+            CREATE OR REPLACE VIEW {view} AS
+            SELECT e.*{columns}
+            FROM {table} e{joins};
+            "
+    )
+}
+
+impl DB {
+    /// Returns the id of an address in the `addresses` table, inserting it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `address`: The address to look up.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the id of the address.
+    pub async fn address_id(&self, address: H160) -> Result<i32> {
+        if let Some(id) = self.addresses.lock().unwrap().get(&address) {
+            return Ok(id);
+        }
+
+        let wrapped: Address = address.into();
+
+        // A concurrent insert of the same address is not visible to the statement that
+        // conflicted with it, but is to the next one.
+        for _ in 0..2 {
+            let row = self
+                .client
+                .query_opt(
+                    "
+                   WITH inserted AS (
+                       INSERT INTO addresses (address) VALUES ($1)
+                       ON CONFLICT (address) DO NOTHING
+                       RETURNING id
+                   )
+                   SELECT id FROM inserted
+                   UNION ALL
+                   SELECT id FROM addresses WHERE address = $1
+                   LIMIT 1
+                   ",
+                    &[&wrapped],
+                )
+                .await?;
+
+            if let Some(row) = row {
+                let id: i32 = row.get("id");
+                self.addresses.lock().unwrap().insert(address, id);
+                return Ok(id);
+            }
+        }
+
+        Err(eyre!("failed to insert address {:?}", address))
+    }
+
+    /// Moves the address ids of the committed transaction into the committed cache.
+    pub(crate) fn commit_addresses(&self) {
+        self.addresses.lock().unwrap().commit();
+    }
+
+    /// Forgets the address ids of a rolled back transaction or savepoint.
+    pub(crate) fn forget_addresses(&self) {
+        self.addresses.lock().unwrap().forget();
+    }
+}
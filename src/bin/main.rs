@@ -1,10 +1,10 @@
 use clap::Parser;
-use eth_logs::command::EthLog;
+use eth_logs::command::Cli;
 use eyre::Result;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    EthLog::parse().run().await?;
+    Cli::parse().run().await?;
 
     Ok(())
 }
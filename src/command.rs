@@ -1,23 +1,231 @@
-use clap::Parser;
-use eyre::Result;
+use clap::{Parser, Subcommand};
+use eyre::{eyre, Result};
+use std::path::PathBuf;
 
+use crate::config::{Backend, Settings};
+use crate::coverage::status;
+use crate::database::DB;
 use crate::decode::initialise;
-use crate::types::generate_types;
+use crate::dynamic::migration;
+use crate::export::{export, ExportFilter, Format};
+use crate::generate::generate;
+use crate::redecode::redecode;
+use crate::storage::sqlite::SqliteStorage;
+use crate::verify::verify;
 
 #[derive(Parser, Debug)]
+pub struct Cli {
+    /// The configuration file.
+    #[clap(long, global = true, default_value = "config.yaml")]
+    config: PathBuf,
+
+    /// The profile whose file, e.g. config.prod.yaml, overrides the configuration file. Read
+    /// from ETHLOG_PROFILE when omitted.
+    #[clap(long, global = true)]
+    profile: Option<String>,
+
+    #[clap(subcommand)]
+    command: EthLog,
+}
+
+impl Cli {
+    pub async fn run(self) -> Result<()> {
+        Settings::select(self.config, self.profile);
+        self.command.run().await
+    }
+}
+
+#[derive(Subcommand, Debug)]
 pub enum EthLog {
     #[clap(about = "Start the indexing.")]
     Run,
 
-    #[clap(about = "Generate types for the pool contract.")]
-    Generate,
+    #[clap(about = "Generate types for the contracts of ABIs.")]
+    Generate {
+        /// An ABI, Foundry or Hardhat artifact, or a directory of them.
+        #[clap(long, default_value = "./abi")]
+        abi: PathBuf,
+
+        /// The name of the contract, from the file by default.
+        #[clap(long)]
+        name: Option<String>,
+
+        /// The file, or the directory for a directory of ABIs, the types are written to.
+        #[clap(long)]
+        out: Option<PathBuf>,
+
+        /// Fail if the written types differ from the ABIs instead of writing them.
+        #[clap(long)]
+        check: bool,
+    },
+
+    #[clap(about = "Export the event tables to files.")]
+    Export {
+        /// The file format to write.
+        #[clap(long, arg_enum)]
+        format: Format,
+
+        /// The directory the files are written to.
+        #[clap(long, default_value = "export")]
+        out: PathBuf,
+
+        #[clap(flatten)]
+        filter: ExportFilter,
+    },
+
+    #[clap(about = "Detach old partitions of the partitioned tables.")]
+    Detach {
+        /// Partitions ending at or below this block are detached.
+        #[clap(long)]
+        before: u64,
+
+        /// Drop the detached partitions instead of keeping them as standalone tables.
+        #[clap(long)]
+        drop: bool,
+    },
+
+    #[clap(about = "Verify the stored events of a block range against the chain.")]
+    Verify {
+        /// The first block to verify.
+        #[clap(long)]
+        from: u64,
+
+        /// The last block to verify.
+        #[clap(long)]
+        to: u64,
+
+        /// Rewrite the stored events to match the chain.
+        #[clap(long)]
+        repair: bool,
+    },
+
+    #[clap(about = "Show the processed block ranges and the gaps between them.")]
+    Status,
+
+    #[clap(about = "Rebuild the event tables from the raw logs.")]
+    Redecode {
+        /// The first block to rebuild.
+        #[clap(long)]
+        from: Option<u64>,
+
+        /// The last block to rebuild.
+        #[clap(long)]
+        to: Option<u64>,
+    },
+
+    #[clap(about = "List the logs that failed to decode or to be written.")]
+    DeadLetters {
+        /// The maximum number of dead letters to list.
+        #[clap(long, default_value = "100")]
+        limit: u32,
+    },
+
+    #[clap(about = "Retry the logs that failed to decode or to be written.")]
+    Retry {
+        /// The dead letter to retry, every one by default.
+        #[clap(long)]
+        id: Option<i32>,
+    },
 }
 
 impl EthLog {
     pub async fn run(self) -> Result<()> {
         match self {
             Self::Run => Ok(initialise().await?),
-            Self::Generate => Ok(generate_types()?),
+            Self::Generate {
+                abi,
+                name,
+                out,
+                check,
+            } => Ok(generate(
+                &abi,
+                name.as_deref(),
+                out.as_deref(),
+                check,
+                migration,
+            )?),
+            Self::Export {
+                format,
+                out,
+                filter,
+            } => Ok(export(&postgres("export").await?, format, &filter, &out).await?),
+            Self::Detach { before, drop } => {
+                postgres("detach")
+                    .await?
+                    .detach_partitions(before, drop)
+                    .await?;
+                Ok(())
+            }
+            Self::Verify { from, to, repair } => {
+                let report = verify(&postgres("verify").await?, from, to, repair).await?;
+                println!("{report}");
+                Ok(())
+            }
+            Self::Status => Ok(status(&postgres("status").await?).await?),
+            Self::Redecode { from, to } => {
+                redecode(&postgres("redecode").await?, from, to).await?;
+                Ok(())
+            }
+            Self::DeadLetters { limit } => {
+                let settings = Settings::new()?;
+                let letters = match settings.database.backend {
+                    Backend::Postgres => {
+                        DB::connect_with(&settings.database)
+                            .await?
+                            .dead_letters(limit)
+                            .await?
+                    }
+                    Backend::Sqlite => {
+                        SqliteStorage::open(&settings.database.path)?
+                            .dead_letters(limit)
+                            .await?
+                    }
+                };
+                for letter in letters {
+                    println!("{letter}");
+                }
+                Ok(())
+            }
+            Self::Retry { id } => {
+                let settings = Settings::new()?;
+                let (written, failed) = match settings.database.backend {
+                    Backend::Postgres => {
+                        DB::connect_with(&settings.database)
+                            .await?
+                            .retry_dead_letters(id)
+                            .await?
+                    }
+                    Backend::Sqlite => {
+                        SqliteStorage::open(&settings.database.path)?
+                            .retry_dead_letters(id)
+                            .await?
+                    }
+                };
+                println!("{written} written, {failed} failed");
+                Ok(())
+            }
         }
     }
 }
+
+/// Connects to the PostgreSQL database for a command only the PostgreSQL backend supports.
+///
+/// # Arguments
+///
+/// - `command`: The name of the command, for the error.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the connected `DB`, or an error if another backend is
+/// configured.
+async fn postgres(command: &str) -> Result<DB> {
+    let settings = Settings::new()?;
+
+    match settings.database.backend {
+        Backend::Postgres => DB::connect_with(&settings.database).await,
+        Backend::Sqlite => Err(eyre!(
+            "{} requires the postgres backend, but database.backend is sqlite",
+            command
+        )),
+    }
+}
@@ -1,41 +1,790 @@
-use config::{Config, File, FileFormat};
-use eyre::Result;
+use config::{
+    builder::DefaultState, Config, ConfigBuilder, ConfigError, Environment, File, FileFormat,
+};
+use ethers::{abi::Abi, types::H160};
+use eyre::{eyre, Result};
 use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::constants::POOL_ADDRESS;
+use crate::error::IndexerError;
+use crate::retention;
+use crate::types::pool_types::USDC_WETH_POOL_ABI;
 
 /// A struct representing configuration settings for the application.
 #[derive(Debug, Deserialize)]
 pub struct Settings {
-    /// The username used for authentication.
-    pub username: String,
+    /// The Ethereum node the logs are read from.
+    #[serde(default)]
+    pub rpc: RpcSettings,
 
-    /// The password used for authentication.
-    pub password: String,
+    /// The chain id the node must serve, not checked when omitted.
+    #[serde(default)]
+    pub chain_id: Option<u64>,
+
+    /// The indexed contracts, the USDC/WETH pool when omitted.
+    #[serde(default = "default_contracts")]
+    pub contracts: Vec<ContractSettings>,
+
+    /// How logs are fetched from the node.
+    #[serde(default)]
+    pub ingest: IngestSettings,
+
+    /// The storage backend and its connection.
+    #[serde(default)]
+    pub database: DatabaseSettings,
+
+    /// The live Parquet sink, disabled when omitted.
+    #[serde(default)]
+    pub parquet: Option<ParquetSettings>,
+
+    /// The retention policy of the PostgreSQL tables, keeping everything when omitted.
+    #[serde(default)]
+    pub retention: Option<RetentionSettings>,
+
+    /// Background backfilling of gaps in the PostgreSQL tables, disabled when omitted.
+    #[serde(default)]
+    pub backfill: Option<BackfillSettings>,
+}
+
+/// The Ethereum node the logs are read from.
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct RpcSettings {
+    /// The WebSocket URL of the node, which usually carries an API key.
+    #[serde(default)]
+    pub url: Secret,
+
+    /// A file holding the URL, read instead of `url` when set.
+    #[serde(default)]
+    pub url_file: Option<PathBuf>,
+}
+
+/// A secret setting, such as a password or an RPC URL with an API key.
+///
+/// Its `Debug` output is redacted, so settings can be logged without leaking it.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Wraps a secret value.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the secret value, to be passed on without being logged.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the secret is unset.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Replaces the secret with the contents of its `_file` setting, if set.
+    ///
+    /// # Arguments
+    ///
+    /// - `key`: The key of the secret, for errors.
+    /// - `file`: The file holding the secret, without its trailing newline.
+    fn read_file(&mut self, key: &str, file: Option<&Path>) -> Result<()> {
+        if let Some(path) = file {
+            let value = fs::read_to_string(path).map_err(|e| {
+                invalid(&format!(
+                    "{key}_file: failed to read {}: {e}",
+                    path.display()
+                ))
+            })?;
+            self.0 = value.trim_end_matches(['\r', '\n']).to_string();
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// A contract to index.
+///
+/// Contracts without an ABI are indexed with the generated pool types, the others from their
+/// ABI at runtime.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContractSettings {
+    /// The name of the contract, prefixing its event tables when indexed by ABI.
+    #[serde(default)]
+    pub name: String,
+
+    /// The address of the deployed contract.
+    pub address: String,
+
+    /// Path of the JSON ABI of the contract, the pool ABI when omitted.
+    #[serde(default)]
+    pub abi: Option<PathBuf>,
+
+    /// The first block to index, from which missed blocks are fetched on startup. Only new
+    /// blocks are indexed when omitted.
+    #[serde(default)]
+    pub start_block: Option<u64>,
+
+    /// The names of the events to index, every event of the ABI when empty.
+    #[serde(default)]
+    pub events: Vec<String>,
+}
+
+impl ContractSettings {
+    /// Reads the ABI of the contract, or returns the pool ABI when it has none.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ABI, or an error if the file cannot be read or parsed.
+    pub fn abi(&self) -> Result<Abi> {
+        let Some(path) = &self.abi else {
+            return Ok(USDC_WETH_POOL_ABI.clone());
+        };
+
+        let json = fs::read_to_string(path)
+            .map_err(|e| eyre!("failed to read ABI {}: {e}", path.display()))?;
+        serde_json::from_str(&json).map_err(|e| eyre!("invalid ABI {}: {e}", path.display()))
+    }
+
+    /// Parses the address of the contract.
+    pub fn address(&self) -> Result<H160> {
+        self.address
+            .parse()
+            .map_err(|e| eyre!("invalid address {}: {e}", self.address))
+    }
+}
+
+fn default_contracts() -> Vec<ContractSettings> {
+    vec![ContractSettings {
+        name: String::new(),
+        address: POOL_ADDRESS.to_string(),
+        abi: None,
+        start_block: None,
+        events: Vec::new(),
+    }]
+}
+
+/// How logs are fetched from the node.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IngestSettings {
+    /// The number of blocks fetched by a single `eth_getLogs` request, 2000 by default.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: u64,
 
-    /// The host address where the application connects.
+    /// The number of `eth_getLogs` requests in flight, 4 by default.
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+
+    /// The number of blocks a block must have on top of it before it is indexed. Logs are
+    /// streamed as they arrive, and reorgs rolled back, when 0, the default.
+    #[serde(default)]
+    pub confirmations: u64,
+}
+
+impl Default for IngestSettings {
+    fn default() -> Self {
+        Self {
+            batch_size: default_batch_size(),
+            concurrency: default_concurrency(),
+            confirmations: 0,
+        }
+    }
+}
+
+fn default_batch_size() -> u64 {
+    2_000
+}
+
+fn default_concurrency() -> usize {
+    4
+}
+
+/// The storage backend events are written to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// A PostgreSQL server, configured by the connection settings.
+    #[default]
+    Postgres,
+
+    /// An embedded SQLite database file.
+    Sqlite,
+}
+
+/// The storage backend and its connection.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DatabaseSettings {
+    /// The storage backend, `postgres` by default.
+    #[serde(default)]
+    pub backend: Backend,
+
+    /// The host of the PostgreSQL server, `localhost` by default.
+    #[serde(default = "default_host")]
     pub host: String,
 
-    /// The port number to establish the connection.
+    /// The port of the PostgreSQL server, 5432 by default.
+    #[serde(default = "default_port")]
     pub port: u16,
 
+    /// The username used for authentication.
+    #[serde(default)]
+    pub username: String,
+
+    /// The password used for authentication.
+    #[serde(default)]
+    pub password: Secret,
+
+    /// A file holding the password, read instead of `password` when set.
+    #[serde(default)]
+    pub password_file: Option<PathBuf>,
+
     /// The name of the Database.
+    #[serde(default)]
     pub name: String,
+
+    /// TLS settings for the database connection, disabled when omitted.
+    #[serde(default)]
+    pub tls: TlsSettings,
+
+    /// Path of the SQLite database file, used by the `sqlite` backend.
+    #[serde(default = "default_sqlite_path")]
+    pub path: PathBuf,
+
+    /// Block range partitioning of the PostgreSQL tables, disabled when omitted.
+    #[serde(default)]
+    pub partitioning: Option<PartitionSettings>,
+
+    /// Whether to keep the topics and data of every log in the `raw_logs` table.
+    #[serde(default)]
+    pub raw_logs: bool,
 }
 
+impl Default for DatabaseSettings {
+    fn default() -> Self {
+        Self {
+            backend: Backend::default(),
+            host: default_host(),
+            port: default_port(),
+            username: String::new(),
+            password: Secret::default(),
+            password_file: None,
+            name: String::new(),
+            tls: TlsSettings::default(),
+            path: default_sqlite_path(),
+            partitioning: None,
+            raw_logs: false,
+        }
+    }
+}
+
+fn default_host() -> String {
+    "localhost".to_string()
+}
+
+fn default_port() -> u16 {
+    5432
+}
+
+/// Block range partitioning of the PostgreSQL tables.
+///
+/// Partitioning only applies when the tables are first created.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PartitionSettings {
+    /// The number of blocks covered by each partition.
+    pub blocks_per_partition: u64,
+}
+
+fn default_sqlite_path() -> PathBuf {
+    PathBuf::from("ethlog.sqlite")
+}
+
+/// Settings of the live Parquet sink, which mirrors the storage backend.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParquetSettings {
+    /// The directory the Parquet files are written to.
+    pub dir: PathBuf,
+
+    /// The number of blocks covered by each file, 10000 by default.
+    #[serde(default = "default_blocks_per_file")]
+    pub blocks_per_file: u64,
+
+    /// The number of blocks kept in memory behind the newest one before a range is written,
+    /// so a reorg up to this depth can be rolled back, 64 by default.
+    #[serde(default = "default_reorg_depth")]
+    pub reorg_depth: u64,
+}
+
+fn default_blocks_per_file() -> u64 {
+    10_000
+}
+
+fn default_reorg_depth() -> u64 {
+    64
+}
+
+/// A retention policy, pruning rows older than a rolling window in the background.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RetentionSettings {
+    /// The number of seconds between two pruning runs, 3600 by default.
+    #[serde(default = "default_retention_interval")]
+    pub interval_secs: u64,
+
+    /// The maximum number of rows deleted per statement, 10000 by default.
+    #[serde(default = "default_retention_batch_size")]
+    pub batch_size: i64,
+
+    /// The retention window in days, keyed by table name. Tables left out are kept forever.
+    pub tables: HashMap<String, u64>,
+}
+
+fn default_retention_interval() -> u64 {
+    3600
+}
+
+fn default_retention_batch_size() -> i64 {
+    10_000
+}
+
+/// Background backfilling of the block ranges missed between runs of the indexer.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BackfillSettings {
+    /// The number of seconds between two searches for gaps, 300 by default.
+    #[serde(default = "default_backfill_interval")]
+    pub interval_secs: u64,
+}
+
+fn default_backfill_interval() -> u64 {
+    300
+}
+
+/// How strictly the database connection should use TLS.
+///
+/// The variants follow the libpq `sslmode` values of the same name.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TlsMode {
+    /// Connect without TLS.
+    #[default]
+    Disable,
+
+    /// Use TLS if the server supports it, without verifying its certificate.
+    Prefer,
+
+    /// Always use TLS. The certificate chain is only verified when `ca_cert` is set.
+    Require,
+
+    /// Always use TLS, verifying the certificate chain and the host name.
+    VerifyFull,
+}
+
+/// TLS configuration for the PostgreSQL connection.
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct TlsSettings {
+    /// The TLS mode, `disable` by default.
+    #[serde(default)]
+    pub mode: TlsMode,
+
+    /// Path to a PEM encoded CA bundle used to verify the server certificate.
+    pub ca_cert: Option<PathBuf>,
+
+    /// Path to a PEM encoded client certificate.
+    pub client_cert: Option<PathBuf>,
+
+    /// Path to the PEM encoded PKCS#8 private key of the client certificate.
+    pub client_key: Option<PathBuf>,
+}
+
+/// The configuration file read by `Settings::new`.
+const DEFAULT_CONFIG: &str = "config.yaml";
+
+/// The prefix of the environment variables overriding settings, e.g. `ETHLOG__DATABASE__HOST`.
+const ENV_PREFIX: &str = "ETHLOG";
+
+/// The environment variable selecting a profile when none is given.
+const PROFILE_VAR: &str = "ETHLOG_PROFILE";
+
+/// The length from which a segment of an RPC URL is taken for an API key.
+const API_KEY_LEN: usize = 16;
+
+/// The configuration file and profile selected on the command line.
+static SELECTED: OnceLock<(PathBuf, Option<String>)> = OnceLock::new();
+
 impl Settings {
-    /// Creates a new `Settings` instance by loading configuration from a YAML file.
+    /// Creates a new `Settings` instance by loading configuration from the selected file, its
+    /// profile and the environment.
+    ///
+    /// The file is `config.yaml` unless another one was selected with `Settings::select`.
     ///
     /// # Returns
     ///
     /// Returns a `Result` containing the parsed `Settings` if successful, or an error if
-    /// configuration parsing fails.
+    /// configuration parsing or validation fails.
     pub fn new() -> Result<Self> {
-        // Create a new configuration builder.
-        let settings = Config::builder()
-            // Add a source for configuration from a YAML file named "config.yaml".
-            .add_source(File::new("config.yaml", FileFormat::Yaml))
-            .build()?;
+        let (path, profile) = SELECTED
+            .get()
+            .cloned()
+            .unwrap_or_else(|| (PathBuf::from(DEFAULT_CONFIG), None));
+
+        Self::load(&path, profile.as_deref(), env::vars().collect())
+    }
+
+    /// Selects the configuration file and profile read by every later `Settings::new`.
+    ///
+    /// Only the first selection of a process is kept.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The configuration file.
+    /// - `profile`: The profile, from `ETHLOG_PROFILE` when `None`.
+    pub fn select(path: PathBuf, profile: Option<String>) {
+        let _ = SELECTED.set((path, profile));
+    }
+
+    /// Loads settings from layered sources, each overriding the previous one:
+    ///
+    /// 1. The configuration file, in any format supported by its extension.
+    /// 2. The file of the profile next to it, e.g. `config.prod.yaml` for the `prod` profile
+    ///    of `config.yaml`.
+    /// 3. The `ETHLOG__` environment variables, with `__` between nested keys, e.g.
+    ///    `ETHLOG__DATABASE__HOST` for `database.host`.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: The configuration file.
+    /// - `profile`: The profile, from `ETHLOG_PROFILE` when `None`.
+    /// - `vars`: The environment variables.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the parsed `Settings`, or an error if a file is missing or
+    /// the settings are invalid.
+    pub fn load(path: &Path, profile: Option<&str>, vars: HashMap<String, String>) -> Result<Self> {
+        let mut builder = Config::builder().add_source(File::from(path));
+
+        let profile = profile.or_else(|| vars.get(PROFILE_VAR).map(String::as_str));
+        if let Some(profile) = profile {
+            builder = builder.add_source(File::from(profile_path(path, profile)));
+        }
+
+        // Variables are kept as strings, so a numeric password keeps its leading zeros
+        let environment = Environment::with_prefix(ENV_PREFIX)
+            .separator("__")
+            .source(Some(vars.into_iter().collect()));
+
+        Self::build(builder, Some(environment))
+    }
+
+    /// Parses and validates settings from YAML text.
+    ///
+    /// # Arguments
+    ///
+    /// - `yaml`: The configuration, in the format of `config.yaml`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the parsed `Settings`, or an error if they are invalid.
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        let builder = Config::builder().add_source(File::from_str(yaml, FileFormat::Yaml));
+        Self::build(builder, None)
+    }
+
+    /// Builds settings from configuration files and the environment, refusing the secrets
+    /// written into the files.
+    fn build(files: ConfigBuilder<DefaultState>, environment: Option<Environment>) -> Result<Self> {
+        refuse_inline_secrets(&files.clone().build().map_err(IndexerError::from)?)?;
+
+        let builder = match environment {
+            Some(environment) => files.add_source(environment),
+            None => files,
+        };
+        let settings = builder.build().map_err(IndexerError::from)?;
 
         // Try to deserialize the configuration into a `Settings` instance.
-        Ok(settings.try_deserialize::<Settings>()?)
+        let mut settings = settings
+            .try_deserialize::<Settings>()
+            .map_err(IndexerError::from)?;
+
+        // Secrets mounted as files take precedence over inline values
+        settings
+            .rpc
+            .url
+            .read_file("rpc.url", settings.rpc.url_file.as_deref())?;
+        settings.database.password.read_file(
+            "database.password",
+            settings.database.password_file.as_deref(),
+        )?;
+
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Whether the contracts are indexed from their ABI at runtime rather than as the pool.
+    pub fn by_abi(&self) -> bool {
+        self.contracts.iter().any(|c| c.abi.is_some())
+    }
+
+    /// Checks the settings, so a bad configuration fails at startup with the offending key.
+    ///
+    /// The node settings are only checked by `validate_rpc`, since most commands never
+    /// connect to the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the settings are valid.
+    pub fn validate(&self) -> Result<()> {
+        self.validate_contracts()?;
+
+        if self.ingest.batch_size == 0 {
+            return Err(invalid("ingest.batch_size must be greater than zero"));
+        }
+        if self.ingest.concurrency == 0 {
+            return Err(invalid("ingest.concurrency must be greater than zero"));
+        }
+
+        self.validate_database()?;
+
+        if self
+            .parquet
+            .as_ref()
+            .is_some_and(|p| p.blocks_per_file == 0)
+        {
+            return Err(invalid("parquet.blocks_per_file must be greater than zero"));
+        }
+        if let Some(retention) = &self.retention {
+            retention::validate(retention).map_err(|e| invalid(&e.to_string()))?;
+        }
+        if self.backfill.as_ref().is_some_and(|b| b.interval_secs == 0) {
+            return Err(invalid("backfill.interval_secs must be greater than zero"));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the node settings, for the commands that connect to the node.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the node settings are valid.
+    pub fn validate_rpc(&self) -> Result<()> {
+        // No node is built in, so an API key never has to live in the source
+        if self.rpc.url.is_empty() {
+            return Err(invalid(
+                "rpc.url is required, set it with ETHLOG__RPC__URL or rpc.url_file",
+            ));
+        }
+        let url = self.rpc.url.expose();
+        if !url.starts_with("ws://") && !url.starts_with("wss://") {
+            return Err(invalid("rpc.url must be a ws:// or wss:// URL"));
+        }
+        if self.chain_id == Some(0) {
+            return Err(invalid("chain_id must be greater than zero"));
+        }
+
+        Ok(())
+    }
+
+    fn validate_contracts(&self) -> Result<()> {
+        if self.contracts.is_empty() {
+            return Err(invalid("contracts must list at least one contract"));
+        }
+        if self.by_abi() && self.contracts.iter().any(|c| c.abi.is_none()) {
+            return Err(invalid(
+                "contracts indexed by ABI cannot be mixed with the pool, set abi on every contract or on none",
+            ));
+        }
+
+        let mut addresses = HashSet::new();
+        let mut abis = HashMap::new();
+        for (i, contract) in self.contracts.iter().enumerate() {
+            let key = format!("contracts[{i}]");
+
+            let address = contract
+                .address()
+                .map_err(|e| invalid(&format!("{key}.address: {e}")))?;
+            if !addresses.insert(address) {
+                return Err(invalid(&format!(
+                    "{key}.address: {} is configured twice",
+                    contract.address
+                )));
+            }
+
+            // Contracts by ABI sharing a name share their tables
+            if let Some(path) = &contract.abi {
+                if contract.name.is_empty() {
+                    return Err(invalid(&format!(
+                        "{key}.name is required for a contract indexed by ABI"
+                    )));
+                }
+                if abis.insert(&contract.name, path).is_some_and(|p| p != path) {
+                    return Err(invalid(&format!(
+                        "{key}.abi: {} is already configured with another ABI",
+                        contract.name
+                    )));
+                }
+            }
+
+            let abi = contract
+                .abi()
+                .map_err(|e| invalid(&format!("{key}.abi: {e}")))?;
+            for event in &contract.events {
+                if !abi.events.contains_key(event) {
+                    return Err(invalid(&format!(
+                        "{key}.events: {event} is not an event of the ABI"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_database(&self) -> Result<()> {
+        let database = &self.database;
+
+        match database.backend {
+            Backend::Postgres => {
+                for (key, value) in [
+                    ("host", &database.host),
+                    ("username", &database.username),
+                    ("name", &database.name),
+                ] {
+                    if value.is_empty() {
+                        return Err(invalid(&format!(
+                            "database.{key} is required by the postgres backend"
+                        )));
+                    }
+                }
+                if database.port == 0 {
+                    return Err(invalid("database.port must be greater than zero"));
+                }
+            }
+            Backend::Sqlite => {
+                for (key, set) in [
+                    ("retention", self.retention.is_some()),
+                    ("backfill", self.backfill.is_some()),
+                    ("database.raw_logs", database.raw_logs),
+                    ("database.partitioning", database.partitioning.is_some()),
+                ] {
+                    if set {
+                        return Err(invalid(&format!(
+                            "{key} is only supported by the postgres backend"
+                        )));
+                    }
+                }
+            }
+        }
+
+        if database
+            .partitioning
+            .as_ref()
+            .is_some_and(|p| p.blocks_per_partition == 0)
+        {
+            return Err(invalid(
+                "database.partitioning.blocks_per_partition must be greater than zero",
+            ));
+        }
+
+        // Contracts by ABI are written by their own indexing loop
+        if self.by_abi() {
+            if database.backend != Backend::Postgres {
+                return Err(invalid(
+                    "contracts indexed by ABI are only supported by the postgres backend",
+                ));
+            }
+            for (key, set) in [
+                ("database.partitioning", database.partitioning.is_some()),
+                ("database.raw_logs", database.raw_logs),
+                ("parquet", self.parquet.is_some()),
+                ("backfill", self.backfill.is_some()),
+            ] {
+                if set {
+                    return Err(invalid(&format!(
+                        "{key} is not supported with contracts indexed by ABI"
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The file of a profile next to a configuration file, e.g. `config.prod.yaml`.
+///
+/// # Arguments
+///
+/// - `path`: The configuration file.
+/// - `profile`: The name of the profile.
+pub fn profile_path(path: &Path, profile: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let file = match path.extension() {
+        Some(extension) => format!("{stem}.{profile}.{}", extension.to_string_lossy()),
+        None => format!("{stem}.{profile}"),
+    };
+
+    path.with_file_name(file)
+}
+
+/// Refuses the secrets written into configuration files, which end up in version control.
+///
+/// The database password is only read from the environment or a file. The RPC URL can only be
+/// written into a file without an API key, such as the URL of a local node.
+///
+/// # Arguments
+///
+/// - `files`: The settings of the configuration files, without the environment.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating whether the files are free of secrets.
+fn refuse_inline_secrets(files: &Config) -> Result<()> {
+    if files
+        .get_string("database.password")
+        .is_ok_and(|p| !p.is_empty())
+    {
+        return Err(invalid(
+            "database.password must not be written in the configuration, set it with ETHLOG__DATABASE__PASSWORD or database.password_file",
+        ));
+    }
+    if files
+        .get_string("rpc.url")
+        .is_ok_and(|url| has_api_key(&url))
+    {
+        return Err(invalid(
+            "rpc.url carries an API key and must not be written in the configuration, set it with ETHLOG__RPC__URL or rpc.url_file",
+        ));
     }
+
+    Ok(())
+}
+
+/// Whether a URL carries credentials: a user, a query, or a path segment long enough to be an
+/// API key, e.g. `wss://eth-mainnet.g.alchemy.com/v2/<key>`.
+///
+/// # Arguments
+///
+/// - `url`: The URL of the node.
+pub fn has_api_key(url: &str) -> bool {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+
+    authority.contains('@')
+        || rest.contains('?')
+        || path.split('/').any(|segment| segment.len() >= API_KEY_LEN)
+}
+
+/// An invalid configuration error.
+fn invalid(message: &str) -> eyre::Report {
+    IndexerError::Config(ConfigError::Message(message.to_string())).into()
 }
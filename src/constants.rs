@@ -1,4 +1,6 @@
-pub const URL: &str = "wss://eth-mainnet.g.alchemy.com/v2/WgzOH_cnePj5IxtlTlqSjyjJxBnNccmR";
+pub const POOL_ADDRESS: &str = "0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640";
 
+// fee tier of the pool, in hundredths of a basis point
+pub const POOL_FEE: u32 = 500;
 
 // avoid rebuilding, with seperate crates
@@ -0,0 +1,304 @@
+use ethers::providers::{Provider, Ws};
+use eyre::{eyre, Result};
+use log::{error, info};
+use std::fmt;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use crate::config::BackfillSettings;
+use crate::database::DB;
+use crate::decode::dead_letter;
+use crate::ingest::{BlockTimes, Source};
+use crate::storage::Storage;
+use crate::verify::fetch_events;
+
+/// A range of blocks, including both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} to {}", self.start, self.end)
+    }
+}
+
+/// Adds a range to a sorted list of disjoint intervals, merging it with the intervals it
+/// overlaps or touches.
+///
+/// # Arguments
+///
+/// - `intervals`: The intervals, sorted by their first block.
+/// - `range`: The range to add.
+pub fn insert(intervals: &mut Vec<Interval>, range: Interval) {
+    let mut merged = range;
+
+    intervals.retain(|i| {
+        let touches = i.start <= merged.end.saturating_add(1) && merged.start <= i.end + 1;
+        if touches {
+            merged.start = merged.start.min(i.start);
+            merged.end = merged.end.max(i.end);
+        }
+        !touches
+    });
+
+    let position = intervals.partition_point(|i| i.start < merged.start);
+    intervals.insert(position, merged);
+}
+
+/// Removes every block above `block_number` from a list of intervals.
+///
+/// # Arguments
+///
+/// - `intervals`: The intervals, sorted by their first block.
+/// - `block_number`: The last block to keep.
+pub fn truncate(intervals: &mut Vec<Interval>, block_number: u64) {
+    intervals.retain(|i| i.start <= block_number);
+    if let Some(last) = intervals.last_mut() {
+        last.end = last.end.min(block_number);
+    }
+}
+
+/// Returns the holes between a sorted list of intervals.
+///
+/// Blocks before the first interval are not a gap, since indexing may have started anywhere.
+///
+/// # Arguments
+///
+/// - `intervals`: The intervals, sorted by their first block.
+pub fn gaps(intervals: &[Interval]) -> Vec<Interval> {
+    let mut gaps = Vec::new();
+    let mut covered_to: Option<u64> = None;
+
+    for interval in intervals {
+        if let Some(end) = covered_to {
+            if interval.start > end + 1 {
+                gaps.push(Interval {
+                    start: end + 1,
+                    end: interval.start - 1,
+                });
+            }
+        }
+        covered_to = covered_to.max(Some(interval.end));
+    }
+
+    gaps
+}
+
+impl DB {
+    /// Marks a range of blocks as processed in the `coverage` table.
+    ///
+    /// The range is merged with every interval it overlaps or touches, so the table holds
+    /// disjoint intervals.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: The first processed block.
+    /// - `to`: The last processed block.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the update.
+    pub async fn cover_range(&self, from: u64, to: u64) -> Result<()> {
+        // LEAST and GREATEST skip the NULLs of an empty aggregate
+        self.client
+            .execute(
+                "
+               WITH merged AS (
+                   DELETE FROM coverage
+                   WHERE start_block <= $2::BIGINT + 1 AND end_block + 1 >= $1::BIGINT
+                   RETURNING start_block, end_block
+               )
+               INSERT INTO coverage (start_block, end_block)
+               SELECT LEAST($1::BIGINT, MIN(start_block)), GREATEST($2::BIGINT, MAX(end_block))
+               FROM merged
+               ",
+                &[&(from as i64), &(to as i64)],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Reads the processed block ranges.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the intervals, sorted by their first block.
+    pub async fn coverage(&self) -> Result<Vec<Interval>> {
+        let rows = self
+            .client
+            .query(
+                "SELECT start_block, end_block FROM coverage ORDER BY start_block",
+                &[],
+            )
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| Interval {
+                start: row.get::<_, i64>("start_block") as u64,
+                end: row.get::<_, i64>("end_block") as u64,
+            })
+            .collect())
+    }
+
+    /// Reads the block ranges missing between the processed ones.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the gaps, sorted by their first block.
+    pub async fn gaps(&self) -> Result<Vec<Interval>> {
+        Ok(gaps(&self.coverage().await?))
+    }
+}
+
+/// Prints the processed block ranges and the gaps between them.
+///
+/// # Arguments
+///
+/// - `db`: A reference to the PostgreSQL database instance.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of reading the coverage.
+pub async fn status(db: &DB) -> Result<()> {
+    let coverage = db.coverage().await?;
+
+    for interval in &coverage {
+        println!("covered {interval}");
+    }
+    for gap in gaps(&coverage) {
+        println!("gap     {gap}");
+    }
+
+    Ok(())
+}
+
+/// Indexes the events of a gap fetched through `eth_getLogs`.
+///
+/// The gap is processed in chunks of `batch_size` blocks, each written and marked as processed
+/// in its own transaction, so an interrupted backfill keeps its progress. Logs that fail to
+/// decode are kept as dead letters, like in the indexer.
+///
+/// # Arguments
+///
+/// - `db`: A reference to the PostgreSQL database instance.
+/// - `provider`: The node the logs are read from.
+/// - `source`: The logs of the pools.
+/// - `gap`: The blocks to backfill.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the backfill.
+pub async fn backfill(
+    db: &DB,
+    provider: &Provider<Ws>,
+    source: &Source,
+    gap: Interval,
+) -> Result<()> {
+    let mut start = gap.start;
+
+    while start <= gap.end {
+        let end = gap.end.min(start + source.batch_size() - 1);
+        let (events, undecodable) = fetch_events(provider, source, start, end).await?;
+
+        db.client.batch_execute("BEGIN").await?;
+
+        let result = async {
+            let mut current_block = None;
+            for chain in &events {
+                let block_number = chain.meta.block_number.as_u64();
+                if current_block != Some(block_number) {
+                    let block_hash = chain.meta.block_hash;
+                    let timestamp = provider.block_time(block_hash).await?;
+                    db.upsert_block(block_number, block_hash, timestamp).await?;
+                    current_block = Some(block_number);
+                }
+                db.insert_event(&chain.meta, &chain.event).await?;
+            }
+            for log in &undecodable {
+                dead_letter(db, &log.meta, Some(&log.log), &log.error).await?;
+            }
+
+            db.cover_range(start, end).await
+        }
+        .await;
+
+        match result {
+            Ok(()) => db.commit_transaction().await?,
+            Err(e) => {
+                db.rollback_transaction().await?;
+                return Err(e);
+            }
+        }
+
+        info!(
+            "Backfilled {} events for blocks {} to {}",
+            events.len(),
+            start,
+            end
+        );
+        start = end + 1;
+    }
+
+    Ok(())
+}
+
+/// Spawns a background task backfilling every gap every `interval_secs` seconds.
+///
+/// The task uses its own database and node connections, so backfills never run inside the
+/// block transactions of the indexer.
+///
+/// # Arguments
+///
+/// - `settings`: The backfill settings from the configuration file.
+/// - `source`: The logs of the pools.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the handle of the task, or an error if the settings are invalid.
+pub fn spawn(settings: BackfillSettings, source: Source) -> Result<JoinHandle<()>> {
+    if settings.interval_secs == 0 {
+        return Err(eyre!("backfill interval_secs must be greater than zero"));
+    }
+
+    Ok(tokio::spawn(async move {
+        let connections = async {
+            let db = DB::new().await?;
+            let provider = source.connect().await?;
+            Ok::<_, eyre::Report>((db, provider))
+        };
+
+        let (db, provider) = match connections.await {
+            Ok(connections) => connections,
+            Err(e) => {
+                error!("Backfill disabled, failed to connect: {}", e);
+                return;
+            }
+        };
+
+        let mut interval = tokio::time::interval(Duration::from_secs(settings.interval_secs));
+        loop {
+            interval.tick().await;
+
+            let gaps = match db.gaps().await {
+                Ok(gaps) => gaps,
+                Err(e) => {
+                    error!("Failed to read gaps: {}", e);
+                    continue;
+                }
+            };
+
+            for gap in gaps {
+                info!("Backfilling blocks {}", gap);
+                if let Err(e) = backfill(&db, &provider, &source, gap).await {
+                    error!("Failed to backfill blocks {}: {}", gap, e);
+                    break;
+                }
+            }
+        }
+    }))
+}
@@ -1,20 +1,70 @@
-use ethers::prelude::LogMeta;
-use eyre::Result;
+use async_trait::async_trait;
+use ethers::{abi::RawLog, prelude::LogMeta, types::H256};
+use eyre::{eyre, Result};
 use log::{error, info};
-use tokio_postgres::{Client, Config, NoTls};
+use native_tls::{Certificate, Identity, TlsConnector};
+use postgres_native_tls::MakeTlsConnector;
+use std::collections::HashSet;
+use std::fs;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, UNIX_EPOCH};
+use tokio_postgres::{
+    config::SslMode,
+    tls::{MakeTlsConnect, TlsConnect},
+    Client, Config, NoTls, Socket,
+};
 
-use crate::config::Settings;
+use crate::addresses::{address_schema, AddressCache};
+use crate::config::{DatabaseSettings, Settings, TlsMode, TlsSettings};
+use crate::export::{fixed, EventKind};
+use crate::indexed::SCHEMAS;
+use crate::notify::Notification;
+use crate::rollup::rollup_tables;
+use crate::storage::Storage;
 use crate::types::pool_types::usdc_weth_pool::*;
-use crate::types::{Address, Wu128, WI256, WU256};
+use crate::types::Address;
 
 /// Represents a PostgreSQL database client and provides methods for database operations.
 #[derive(Debug)]
 pub struct DB {
     /// The PostgreSQL database client.
     pub client: Client,
+
+    /// Whether written events are announced to listeners with `NOTIFY`.
+    notifications: bool,
+
+    /// The address ids known to this connection.
+    pub(crate) addresses: Mutex<AddressCache>,
+
+    /// The partitions known to this connection.
+    partitions: Mutex<PartitionCache>,
 }
 
 impl DB {
+    /// Creates a `DB` instance on an established connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `client`: The PostgreSQL database client.
+    pub fn from_client(client: Client) -> Self {
+        Self {
+            client,
+            notifications: false,
+            addresses: Mutex::new(AddressCache::default()),
+            partitions: Mutex::new(PartitionCache::default()),
+        }
+    }
+
+    /// Announces every event written through `Storage::insert_event` to listeners.
+    ///
+    /// Only the live indexer sends notifications, so events rewritten by backfill, `redecode`,
+    /// `verify --repair` or `retry` are not announced again.
+    pub fn with_notifications(mut self) -> Self {
+        self.notifications = true;
+        self
+    }
+
     /// Creates a new `DB` instance and establishes a connection to the PostgreSQL database.
     ///
     /// This function reads database connection settings from the configuration file and initializes
@@ -22,35 +72,81 @@ impl DB {
     ///
     /// # Returns
     ///
-    /// Returns a `Result` indicating the success or failure of the database initialization.
+    /// Returns a `Result` containing the connected `DB`, or an error if the configuration cannot
+    /// be read or the connection fails.
     pub async fn new() -> Result<Self> {
         // Read database connection settings from the configuration file.
-        let Settings {
+        let settings = Settings::new()?.database;
+
+        Self::connect_with(&settings).await
+    }
+
+    /// Creates a new `DB` instance connected with the given database settings.
+    ///
+    /// # Arguments
+    ///
+    /// - `settings`: The database connection settings.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the connected `DB`.
+    pub async fn connect_with(settings: &DatabaseSettings) -> Result<Self> {
+        let DatabaseSettings {
             username,
             password,
             host,
             port,
             name,
-        } = Settings::new().expect("failed to read config");
-
-
-        //builder pattern
-        // builder.config,
-        // set the parts, .host,
-        // test cases good for and different config
-        // spawn bckground and tokio test
+            tls,
+            ..
+        } = settings;
 
         // Configure the database connection.
         let mut config = Config::new();
         config
-            .host(&host)
-            .port(port)
-            .user(&username)
-            .password(&password)
-            .dbname(&name);
+            .host(host)
+            .port(*port)
+            .user(username)
+            .password(password.expose())
+            .dbname(name);
 
-        // Establish a connection to the database.
-        let (client, connection) = config.connect(NoTls).await?;
+        // Establish a connection to the database, over TLS unless it is disabled.
+        let client = match tls.mode {
+            TlsMode::Disable => Self::connect(&config, NoTls).await?,
+            TlsMode::Prefer => {
+                config.ssl_mode(SslMode::Prefer);
+                Self::connect(&config, Self::tls_connector(tls)?).await?
+            }
+            TlsMode::Require | TlsMode::VerifyFull => {
+                config.ssl_mode(SslMode::Require);
+                Self::connect(&config, Self::tls_connector(tls)?).await?
+            }
+        };
+
+        // Log a successful database connection.
+        info!("Database connection established successfully.");
+
+        Ok(Self::from_client(client))
+    }
+
+    /// Connects to the database and spawns the connection onto the tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: The PostgreSQL connection configuration.
+    /// - `tls`: The TLS connector, or `NoTls` for plaintext connections.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the connected client.
+    async fn connect<T>(config: &Config, tls: T) -> Result<Client>
+    where
+        T: MakeTlsConnect<Socket>,
+        T::Stream: Send + 'static,
+        T::TlsConnect: Send,
+        <T::TlsConnect as TlsConnect<Socket>>::Future: Send,
+    {
+        let (client, connection) = config.connect(tls).await?;
 
         // Spawn a tokio task to handle potential connection errors.
         tokio::spawn(async move {
@@ -59,16 +155,56 @@ impl DB {
             }
         });
 
-        // Log a successful database connection.
-        info!("Database connection established successfully.");
+        Ok(client)
+    }
 
-        Ok(Self { client })
+    /// Builds a TLS connector from the TLS settings.
+    ///
+    /// The CA bundle is added to the trusted roots, and the client certificate and key are
+    /// used as the client identity when both are set. Certificate verification is relaxed
+    /// to match the libpq semantics of the selected mode.
+    ///
+    /// # Arguments
+    ///
+    /// - `tls`: The TLS settings from the configuration file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the connector, or an error if a certificate cannot be read.
+    fn tls_connector(tls: &TlsSettings) -> Result<MakeTlsConnector> {
+        let mut builder = TlsConnector::builder();
+
+        if let Some(path) = &tls.ca_cert {
+            let pem = fs::read(path)?;
+            for cert in Certificate::stack_from_pem(&pem)? {
+                builder.add_root_certificate(cert);
+            }
+        }
+
+        match (&tls.client_cert, &tls.client_key) {
+            (Some(cert), Some(key)) => {
+                let identity = Identity::from_pkcs8(&fs::read(cert)?, &fs::read(key)?)?;
+                builder.identity(identity);
+            }
+            (None, None) => {}
+            _ => return Err(eyre!("both client_cert and client_key must be set")),
+        }
+
+        // Only `verify-full` checks the host name, and `prefer` never checks the chain
+        if tls.mode != TlsMode::VerifyFull {
+            builder
+                .danger_accept_invalid_certs(tls.mode == TlsMode::Prefer || tls.ca_cert.is_none())
+                .danger_accept_invalid_hostnames(true);
+        }
+
+        Ok(MakeTlsConnector::new(builder.build()?))
     }
 
     /// Creates database tables if they do not already exist.
     ///
     /// This method creates tables for various event logs, such as Ethereum transaction logs, swap logs,
-    /// burn logs, mint logs, and flash logs. If the tables already exist, this operation is a no-op.
+    /// burn logs, mint logs, and flash logs, along with the table of committed blocks. If the tables
+    /// already exist, this operation is a no-op.
     ///
     /// # Returns
     ///
@@ -77,74 +213,558 @@ impl DB {
         self.client
             .batch_execute(
                 "
+            CREATE TABLE IF NOT EXISTS blocks (
+                number BIGINT PRIMARY KEY,
+                hash BYTEA NOT NULL,
+                timestamp TIMESTAMPTZ
+            );
+
+            CREATE TABLE IF NOT EXISTS addresses (
+                id SERIAL PRIMARY KEY,
+                address BYTEA UNIQUE NOT NULL
+            );
+
             CREATE TABLE IF NOT EXISTS ethereum_logs (
                 id SERIAL PRIMARY KEY,
                 transaction_hash BYTEA NOT NULL,
                 block_number BYTEA NOT NULL,
                 address BYTEA NOT NULL,
+                log_index BIGINT,
+                transaction_index BIGINT,
                 timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
             );
+            ",
+            )
+            .await?;
+
+        // Event tables are generated from their structs with `#[derive(IndexedEvent)]`
+        let events: String = SCHEMAS.iter().map(|s| s.create_table(false)).collect();
+        self.client.batch_execute(&events).await?;
 
-            CREATE TABLE IF NOT EXISTS swap_logs (
+        self.client
+            .batch_execute(
+                "
+            CREATE TABLE IF NOT EXISTS raw_logs (
                 id SERIAL PRIMARY KEY,
-                ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
-                sender_address BYTEA NOT NULL,
-                receiver_address BYTEA NOT NULL,
-                amount0 BYTEA NOT NULL,
-                amount1 BYTEA NOT NULL,
-                sqrt_price_x96 BYTEA NOT NULL,
-                liquidity BYTEA NOT NULL,
-                tick INT NOT NULL,
+                block_number BYTEA NOT NULL,
+                block_hash BYTEA NOT NULL,
+                transaction_hash BYTEA NOT NULL,
+                transaction_index BIGINT NOT NULL,
+                log_index BIGINT NOT NULL,
+                address BYTEA NOT NULL,
+                topic0 BYTEA,
+                topic1 BYTEA,
+                topic2 BYTEA,
+                topic3 BYTEA,
+                data BYTEA NOT NULL,
                 timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
             );
 
-            CREATE TABLE IF NOT EXISTS burn_logs (
-                id SERIAL PRIMARY KEY,
-                ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
-                owner_address BYTEA NOT NULL,
-                tick_lower INT NOT NULL,
-                tick_upper INT NOT NULL,
-                amount BYTEA NOT NULL,
-                amount0 BYTEA NOT NULL,
-                amount1 BYTEA NOT NULL,
-                timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
+            -- Tables created before event rows carried their block number
+            ALTER TABLE swap_logs ADD COLUMN IF NOT EXISTS block_number BYTEA;
+            ALTER TABLE burn_logs ADD COLUMN IF NOT EXISTS block_number BYTEA;
+            ALTER TABLE mint_logs ADD COLUMN IF NOT EXISTS block_number BYTEA;
+            ALTER TABLE flash_logs ADD COLUMN IF NOT EXISTS block_number BYTEA;
+
+            -- Tables created before logs carried their position in the block
+            ALTER TABLE ethereum_logs ADD COLUMN IF NOT EXISTS log_index BIGINT;
+
+            -- Tables created before logs carried the position of their transaction
+            ALTER TABLE ethereum_logs ADD COLUMN IF NOT EXISTS transaction_index BIGINT;
+
+            -- Tables created before blocks carried their timestamp
+            ALTER TABLE blocks ADD COLUMN IF NOT EXISTS timestamp TIMESTAMPTZ;
+            CREATE INDEX IF NOT EXISTS blocks_timestamp_idx ON blocks (timestamp);
+
+            CREATE TABLE IF NOT EXISTS partitioning (
+                blocks_per_partition BIGINT NOT NULL
             );
 
-            CREATE TABLE IF NOT EXISTS mint_logs (
+            CREATE TABLE IF NOT EXISTS dead_letters (
                 id SERIAL PRIMARY KEY,
-                ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
-                sender_address BYTEA NOT NULL,
-                owner_address BYTEA NOT NULL,
-                tick_lower INT NOT NULL,
-                tick_upper INT NOT NULL,
-                amount BYTEA NOT NULL,
-                amount0 BYTEA NOT NULL,
-                amount1 BYTEA NOT NULL,
+                block_number BYTEA NOT NULL,
+                block_hash BYTEA NOT NULL,
+                transaction_hash BYTEA NOT NULL,
+                transaction_index BIGINT NOT NULL,
+                log_index BIGINT NOT NULL,
+                address BYTEA NOT NULL,
+                topic0 BYTEA,
+                topic1 BYTEA,
+                topic2 BYTEA,
+                topic3 BYTEA,
+                data BYTEA,
+                error TEXT NOT NULL,
+                retries INT DEFAULT 0 NOT NULL,
                 timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
             );
 
-            CREATE TABLE IF NOT EXISTS flash_logs (
-                id SERIAL PRIMARY KEY,
-                ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
-                sender_address BYTEA NOT NULL,
-                receiver_address BYTEA NOT NULL,
-                amount0 BYTEA NOT NULL,
-                amount1 BYTEA NOT NULL,
-                paid0 BYTEA NOT NULL,
-                paid1 BYTEA NOT NULL,
-                timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
+            CREATE TABLE IF NOT EXISTS coverage (
+                start_block BIGINT NOT NULL,
+                end_block BIGINT NOT NULL
             );
 
+            CREATE INDEX IF NOT EXISTS ethereum_logs_block_number_idx ON ethereum_logs (block_number);
+            CREATE INDEX IF NOT EXISTS raw_logs_block_number_idx ON raw_logs (block_number, log_index);
+
             ",
             )
             .await?;
 
+        self.client.batch_execute(&address_schema()).await?;
+
+        // Indexes on address ids follow the migration of the address columns
+        let indexes: String = SCHEMAS.iter().map(|s| s.create_indexes()).collect();
+        self.client.batch_execute(&indexes).await?;
+        self.client.batch_execute(&rollup_tables()).await?;
+
         // Log a message indicating successful table creation.
         info!("Tables created successfully");
 
         Ok(())
     }
 
+    /// Creates the log and event tables partitioned by block range, if they do not already exist.
+    ///
+    /// Each table is range partitioned on `block_number`, and a partition of every table is
+    /// created for each `blocks_per_partition` blocks as the chain advances. Partitioned event
+    /// tables have no foreign key to `ethereum_logs`, so rows are removed by block number on
+    /// rollback instead of through the cascade. Call this before `create_table`, which adds the
+    /// remaining tables and indexes.
+    ///
+    /// # Arguments
+    ///
+    /// - `blocks_per_partition`: The number of blocks covered by each partition.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the table creation.
+    pub async fn create_partitioned_table(&self, blocks_per_partition: u64) -> Result<()> {
+        if blocks_per_partition == 0 {
+            return Err(eyre!("blocks_per_partition must be greater than zero"));
+        }
+
+        // `CREATE TABLE IF NOT EXISTS` would silently keep tables created unpartitioned
+        self.refuse_unpartitioned().await?;
+
+        self.client
+            .batch_execute(
+                "
+            CREATE TABLE IF NOT EXISTS partitioning (
+                blocks_per_partition BIGINT NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS addresses (
+                id SERIAL PRIMARY KEY,
+                address BYTEA UNIQUE NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS ethereum_logs (
+                id SERIAL,
+                transaction_hash BYTEA NOT NULL,
+                block_number BYTEA NOT NULL,
+                address BYTEA NOT NULL,
+                log_index BIGINT,
+                transaction_index BIGINT,
+                timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL,
+                PRIMARY KEY (id, block_number)
+            ) PARTITION BY RANGE (block_number);
+            ",
+            )
+            .await?;
+
+        // Event tables are generated from their structs with `#[derive(IndexedEvent)]`
+        let events: String = SCHEMAS.iter().map(|s| s.create_table(true)).collect();
+        self.client.batch_execute(&events).await?;
+
+        self.client
+            .batch_execute(
+                "
+            CREATE TABLE IF NOT EXISTS raw_logs (
+                id SERIAL,
+                block_number BYTEA NOT NULL,
+                block_hash BYTEA NOT NULL,
+                transaction_hash BYTEA NOT NULL,
+                transaction_index BIGINT NOT NULL,
+                log_index BIGINT NOT NULL,
+                address BYTEA NOT NULL,
+                topic0 BYTEA,
+                topic1 BYTEA,
+                topic2 BYTEA,
+                topic3 BYTEA,
+                data BYTEA NOT NULL,
+                timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL,
+                PRIMARY KEY (id, block_number)
+            ) PARTITION BY RANGE (block_number);
+            ",
+            )
+            .await?;
+
+        // The partition size is part of the schema, so it is stored with it.
+        let existing = self
+            .client
+            .query_opt("SELECT blocks_per_partition FROM partitioning", &[])
+            .await?;
+
+        match existing.map(|row| row.get::<_, i64>("blocks_per_partition") as u64) {
+            Some(n) if n != blocks_per_partition => {
+                return Err(eyre!(
+                    "tables are partitioned every {} blocks, not {}",
+                    n,
+                    blocks_per_partition
+                ))
+            }
+            Some(_) => {}
+            None => {
+                self.client
+                    .execute(
+                        "INSERT INTO partitioning (blocks_per_partition) VALUES ($1)",
+                        &[&(blocks_per_partition as i64)],
+                    )
+                    .await?;
+            }
+        }
+
+        self.partitions.lock().unwrap().size = Some(Some(blocks_per_partition));
+
+        info!(
+            "Partitioned tables created every {} blocks",
+            blocks_per_partition
+        );
+
+        Ok(())
+    }
+
+    /// Writes a block into the `blocks` table, creating its partitions first if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_number`: The number of the block.
+    /// - `block_hash`: The hash of the block.
+    /// - `timestamp`: The timestamp of the block in seconds since the epoch, if known.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the insertion.
+    pub(crate) async fn upsert_block(
+        &self,
+        block_number: u64,
+        block_hash: H256,
+        timestamp: Option<u64>,
+    ) -> Result<()> {
+        self.ensure_partition(block_number).await?;
+
+        let timestamp = timestamp.map(|t| UNIX_EPOCH + Duration::from_secs(t));
+        self.client
+            .execute(
+                "
+               INSERT INTO blocks (number, hash, timestamp)
+               VALUES ($1, $2, $3)
+               ON CONFLICT (number) DO UPDATE
+               SET hash = EXCLUDED.hash, timestamp = COALESCE(EXCLUDED.timestamp, blocks.timestamp)
+               ",
+                &[&(block_number as i64), &block_hash.as_bytes(), &timestamp],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Runs a write inside a savepoint of the open transaction.
+    ///
+    /// A failed statement aborts the whole PostgreSQL transaction, so the write is rolled back
+    /// to the savepoint on error, leaving the rest of the block usable.
+    ///
+    /// # Arguments
+    ///
+    /// - `write`: The statements to run.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the output of the write.
+    pub(crate) async fn savepoint<T>(&self, write: impl Future<Output = Result<T>>) -> Result<T> {
+        self.client.batch_execute("SAVEPOINT write").await?;
+
+        match write.await {
+            Ok(value) => {
+                self.client.batch_execute("RELEASE SAVEPOINT write").await?;
+                Ok(value)
+            }
+            Err(e) => {
+                self.client
+                    .batch_execute("ROLLBACK TO SAVEPOINT write")
+                    .await?;
+                self.forget_addresses();
+                Err(e)
+            }
+        }
+    }
+
+    /// Commits the open transaction.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the commit.
+    pub(crate) async fn commit_transaction(&self) -> Result<()> {
+        match self.client.batch_execute("COMMIT").await {
+            Ok(()) => {
+                self.commit_addresses();
+                self.partitions.lock().unwrap().commit();
+                Ok(())
+            }
+            Err(e) => {
+                self.forget_addresses();
+                self.partitions.lock().unwrap().forget();
+                Err(e.into())
+            }
+        }
+    }
+
+    /// Rolls back the open transaction.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the rollback.
+    pub(crate) async fn rollback_transaction(&self) -> Result<()> {
+        self.forget_addresses();
+        self.partitions.lock().unwrap().forget();
+        self.client.batch_execute("ROLLBACK").await?;
+        Ok(())
+    }
+
+    /// Deletes every block above `block_number` along with its events, raw logs, dead letters
+    /// and rollup contributions, and trims the coverage to it, inside the open transaction.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_number`: The last block to keep.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the deletion.
+    async fn delete_above(&self, block_number: u64) -> Result<()> {
+        for kind in EventKind::ALL {
+            self.reverse_rollups(
+                kind,
+                "e.block_number > $1",
+                &[&&block_number.to_be_bytes()[..]],
+            )
+            .await?;
+        }
+
+        // Partitioned event tables have no foreign keys to cascade through, so every table is
+        // cleared by block number.
+        for table in PARTITIONED_TABLES.iter().rev() {
+            self.client
+                .execute(
+                    &format!("DELETE FROM {table} WHERE block_number > $1"),
+                    &[&&block_number.to_be_bytes()[..]],
+                )
+                .await?;
+        }
+
+        self.client
+            .execute(
+                "DELETE FROM dead_letters WHERE block_number > $1",
+                &[&&block_number.to_be_bytes()[..]],
+            )
+            .await?;
+
+        self.client
+            .execute(
+                "DELETE FROM blocks WHERE number > $1",
+                &[&(block_number as i64)],
+            )
+            .await?;
+
+        self.client
+            .batch_execute(&format!(
+                "
+               DELETE FROM coverage WHERE start_block > {block_number};
+               UPDATE coverage SET end_block = {block_number} WHERE end_block > {block_number};
+               "
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Creates the partitions holding `block_number`, if the tables are partitioned.
+    ///
+    /// The partition size and the partitions known to exist are cached, so the catalog is only
+    /// queried when a block starts a partition this connection has not seen yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_number`: The block about to be inserted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the partition creation.
+    pub(crate) async fn ensure_partition(&self, block_number: u64) -> Result<()> {
+        let cached = self.partitions.lock().unwrap().size;
+        let size = match cached {
+            Some(size) => size,
+            None => {
+                let size = self
+                    .client
+                    .query_opt("SELECT blocks_per_partition FROM partitioning", &[])
+                    .await?
+                    .map(|row| row.get::<_, i64>("blocks_per_partition") as u64);
+                self.partitions.lock().unwrap().size = Some(size);
+                size
+            }
+        };
+        let Some(size) = size else {
+            return Ok(());
+        };
+
+        let start = block_number - block_number % size;
+        if self.partitions.lock().unwrap().contains(start) {
+            return Ok(());
+        }
+
+        let exists: bool = self
+            .client
+            .query_one(
+                "SELECT to_regclass($1) IS NOT NULL AS exists",
+                &[&partition_name(LAST_PARTITIONED_TABLE, start)],
+            )
+            .await?
+            .get("exists");
+        if exists {
+            self.partitions.lock().unwrap().insert(start);
+            return Ok(());
+        }
+
+        self.refuse_unpartitioned().await?;
+
+        for table in PARTITIONED_TABLES {
+            self.client
+                .batch_execute(&format!(
+                    "CREATE TABLE IF NOT EXISTS {} PARTITION OF {} FOR VALUES FROM ({}) TO ({})",
+                    partition_name(table, start),
+                    table,
+                    bytea_literal(start),
+                    bytea_literal(start + size),
+                ))
+                .await?;
+        }
+
+        // Only known to exist once the open transaction commits
+        self.partitions.lock().unwrap().insert(start);
+
+        info!(
+            "Created partitions for blocks {} to {}",
+            start,
+            start + size - 1
+        );
+
+        Ok(())
+    }
+
+    /// Fails if any of the tables to partition already exists as a plain table.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether every existing table is partitioned.
+    async fn refuse_unpartitioned(&self) -> Result<()> {
+        let rows = self
+            .client
+            .query(
+                "
+               SELECT c.relname AS name
+               FROM pg_class c
+               LEFT JOIN pg_partitioned_table p ON p.partrelid = c.oid
+               WHERE c.relname = ANY($1) AND c.relkind IN ('r', 'p') AND p.partrelid IS NULL
+                 AND pg_table_is_visible(c.oid)
+               ORDER BY c.relname
+               ",
+                &[&PARTITIONED_TABLES.as_slice()],
+            )
+            .await?;
+
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let names: Vec<String> = rows.iter().map(|row| row.get("name")).collect();
+        Err(eyre!(
+            "{} already exist without partitions; migrate them or use a new database",
+            names.join(", ")
+        ))
+    }
+
+    /// Detaches every partition holding only blocks below `before_block`.
+    ///
+    /// Detached partitions are kept as standalone tables, so they can be archived with
+    /// `pg_dump` and dropped later, or dropped right away with `drop`.
+    ///
+    /// # Arguments
+    ///
+    /// - `before_block`: Partitions ending at or below this block are detached.
+    /// - `drop`: Drop the partitions instead of keeping them as standalone tables.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the names of the detached partitions.
+    pub async fn detach_partitions(&self, before_block: u64, drop: bool) -> Result<Vec<String>> {
+        let Some(row) = self
+            .client
+            .query_opt("SELECT blocks_per_partition FROM partitioning", &[])
+            .await?
+        else {
+            return Err(eyre!("tables are not partitioned"));
+        };
+        let size = row.get::<_, i64>("blocks_per_partition") as u64;
+
+        let mut detached = Vec::new();
+
+        for table in PARTITIONED_TABLES {
+            let partitions = self
+                .client
+                .query(
+                    "
+                   SELECT c.relname AS name
+                   FROM pg_inherits i
+                   JOIN pg_class c ON c.oid = i.inhrelid
+                   JOIN pg_class p ON p.oid = i.inhparent
+                   WHERE p.relname = $1
+                   ORDER BY c.relname
+                   ",
+                    &[&table],
+                )
+                .await?;
+
+            for partition in partitions {
+                let name: String = partition.get("name");
+                let start = name
+                    .strip_prefix(&format!("{table}_p"))
+                    .and_then(|start| start.parse::<u64>().ok());
+
+                // Partitions not created by the indexer are left alone
+                let Some(start) = start else { continue };
+                if start + size > before_block {
+                    continue;
+                }
+
+                self.client
+                    .batch_execute(&format!("ALTER TABLE {table} DETACH PARTITION {name}"))
+                    .await?;
+                if drop {
+                    self.client
+                        .batch_execute(&format!("DROP TABLE {name}"))
+                        .await?;
+                }
+
+                self.partitions.lock().unwrap().remove(start);
+                info!("Detached partition {}", name);
+                detached.push(name);
+            }
+        }
+
+        Ok(detached)
+    }
+
     /// Inserts Ethereum transaction logs into the database.
     ///
     /// This method inserts transaction logs, including transaction hash, block number, and address,
@@ -156,24 +776,30 @@ impl DB {
     ///
     /// # Returns
     ///
-    /// Returns a `Result` indicating the success or failure of the insertion.
-    pub async fn insert_transaction_logs(&self, meta: LogMeta) -> Result<()> {
+    /// Returns a `Result` containing the id of the inserted row, which event rows reference.
+    pub async fn insert_transaction_logs(&self, meta: &LogMeta) -> Result<i32> {
         let address: Address = meta.address.into();
 
-        self.client
-            .execute(
+        let row = self
+            .client
+            .query_one(
                 "
                INSERT INTO ethereum_logs (
                    transaction_hash,
                    block_number,
-                   address
+                   address,
+                   log_index,
+                   transaction_index
                )
-               VALUES ($1, $2, $3)
+               VALUES ($1, $2, $3, $4, $5)
+               RETURNING id
                ",
                 &[
                     &meta.transaction_hash.as_bytes(),
                     &&meta.block_number.as_u64().to_be_bytes()[..],
                     &address,
+                    &(meta.log_index.as_u64() as i64),
+                    &(meta.transaction_index.as_u64() as i64),
                 ],
             )
             .await?;
@@ -181,7 +807,7 @@ impl DB {
         // Log a message indicating the insertion of the transaction event.
         info!("Inserting transaction event: {:?}", meta);
 
-        Ok(())
+        Ok(row.get("id"))
     }
 
     /// Inserts swap event logs into the database.
@@ -191,41 +817,27 @@ impl DB {
     ///
     /// # Arguments
     ///
+    /// - `ethereum_log_id`: The id of the `ethereum_logs` row the event belongs to, if any.
+    /// - `block_number`: The number of the block containing the log, if known.
     /// - `events`: SwapFilter containing swap event log data.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` indicating the success or failure of the insertion.
-    pub async fn insert_swap_event(&self, events: SwapFilter) -> Result<()> {
-        let sender: Address = events.sender.into();
-        let rec: Address = events.recipient.into();
-        let amount0: WI256 = events.amount_0.into();
-        let amount1: WI256 = events.amount_1.into();
-        let sqrt: WU256 = events.sqrt_price_x96.into();
-        let liq: Wu128 = events.liquidity.into();
-
-        self.client
-            .execute(
-                "
-               INSERT INTO swap_logs (
-                   sender_address,
-                   receiver_address,
-                   amount0,
-                   amount1,
-                   sqrt_price_x96,
-                   liquidity,
-                   tick
-               )
-               VALUES ($1, $2, $3, $4, $5, $6, $7)
-               ",
-                &[&sender, &rec, &amount0, &amount1, &sqrt, &liq, &events.tick],
-            )
+    /// Returns a `Result` containing the id of the inserted row.
+    pub async fn insert_swap_event(
+        &self,
+        ethereum_log_id: Option<i32>,
+        block_number: Option<u64>,
+        events: SwapFilter,
+    ) -> Result<i32> {
+        let id = self
+            .insert_indexed(ethereum_log_id, block_number, &events)
             .await?;
 
         // Log a message indicating the insertion of the Swap event.
         info!("Inserting Swap event: {:?}", events);
 
-        Ok(())
+        Ok(id)
     }
     // ...
 
@@ -236,45 +848,27 @@ impl DB {
     ///
     /// # Arguments
     ///
+    /// - `ethereum_log_id`: The id of the `ethereum_logs` row the event belongs to, if any.
+    /// - `block_number`: The number of the block containing the log, if known.
     /// - `events`: BurnFilter containing burn event log data.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` indicating the success or failure of the insertion.
-    pub async fn insert_burn_event(&self, events: BurnFilter) -> Result<()> {
-        let owner: Address = events.owner.into();
-        let amount: Wu128 = events.amount.into();
-        let amount0: WU256 = events.amount_0.into();
-        let amount1: WU256 = events.amount_1.into();
-
-        self.client
-            .execute(
-                "
-               INSERT INTO burn_logs (
-                   owner_address,
-                   tick_lower,
-                   tick_upper,
-                   amount,
-                   amount0,
-                   amount1
-               )
-               VALUES ($1, $2, $3, $4, $5, $6)
-               ",
-                &[
-                    &owner,
-                    &events.tick_lower,
-                    &events.tick_upper,
-                    &amount,
-                    &amount0,
-                    &amount1,
-                ],
-            )
+    /// Returns a `Result` containing the id of the inserted row.
+    pub async fn insert_burn_event(
+        &self,
+        ethereum_log_id: Option<i32>,
+        block_number: Option<u64>,
+        events: BurnFilter,
+    ) -> Result<i32> {
+        let id = self
+            .insert_indexed(ethereum_log_id, block_number, &events)
             .await?;
 
         // Log a message indicating the insertion of the Burn event.
         info!("Inserting Burn event: {:?}", events);
 
-        Ok(())
+        Ok(id)
     }
 
     /// Inserts mint event logs into the database.
@@ -284,47 +878,27 @@ impl DB {
     ///
     /// # Arguments
     ///
+    /// - `ethereum_log_id`: The id of the `ethereum_logs` row the event belongs to, if any.
+    /// - `block_number`: The number of the block containing the log, if known.
     /// - `events`: MintFilter containing mint event log data.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` indicating the success or failure of the insertion.
-    pub async fn insert_mint_event(&self, events: MintFilter) -> Result<()> {
-        let sender: Address = events.sender.into();
-        let owner: Address = events.owner.into();
-        let amount: Wu128 = events.amount.into();
-        let amount0: WU256 = events.amount_0.into();
-        let amount1: WU256 = events.amount_1.into();
-        self.client
-            .execute(
-                "
-               INSERT INTO mint_logs (
-                   sender_address,
-                   owner_address,
-                   tick_lower,
-                   tick_upper,
-                   amount,
-                   amount0,
-                   amount1
-               )
-               VALUES ($1, $2, $3, $4, $5, $6, $7)
-               ",
-                &[
-                    &sender,
-                    &owner,
-                    &events.tick_lower,
-                    &events.tick_upper,
-                    &amount,
-                    &amount0,
-                    &amount1,
-                ],
-            )
+    /// Returns a `Result` containing the id of the inserted row.
+    pub async fn insert_mint_event(
+        &self,
+        ethereum_log_id: Option<i32>,
+        block_number: Option<u64>,
+        events: MintFilter,
+    ) -> Result<i32> {
+        let id = self
+            .insert_indexed(ethereum_log_id, block_number, &events)
             .await?;
 
         // Log a message indicating the insertion of the Mint event.
         info!("Inserting Mint event: {:?}", events);
 
-        Ok(())
+        Ok(id)
     }
 
     /// Inserts flash event logs into the database.
@@ -334,39 +908,288 @@ impl DB {
     ///
     /// # Arguments
     ///
+    /// - `ethereum_log_id`: The id of the `ethereum_logs` row the event belongs to, if any.
+    /// - `block_number`: The number of the block containing the log, if known.
     /// - `events`: FlashFilter containing flash event log data.
     ///
     /// # Returns
     ///
-    /// Returns a `Result` indicating the success or failure of the insertion.
-    pub async fn insert_flash_event(&self, events: FlashFilter) -> Result<()> {
-        let sender: Address = events.sender.into();
-        let rec: Address = events.recipient.into();
-        let amount0: WU256 = events.amount_0.into();
-        let amount1: WU256 = events.amount_1.into();
-        let paid0: WU256 = events.amount_0.into();
-        let paid1: WU256 = events.amount_1.into();
+    /// Returns a `Result` containing the id of the inserted row.
+    pub async fn insert_flash_event(
+        &self,
+        ethereum_log_id: Option<i32>,
+        block_number: Option<u64>,
+        events: FlashFilter,
+    ) -> Result<i32> {
+        let id = self
+            .insert_indexed(ethereum_log_id, block_number, &events)
+            .await?;
+
+        // Log a message indicating the insertion of the Flash event.
+        info!("Inserting Flash event: {:?}", events);
+
+        Ok(id)
+    }
+}
+
+#[async_trait]
+impl Storage for DB {
+    async fn create_table(&self) -> Result<()> {
+        DB::create_table(self).await
+    }
+
+    async fn insert_block(
+        &self,
+        block_number: u64,
+        block_hash: H256,
+        timestamp: Option<u64>,
+    ) -> Result<()> {
+        // Everything up to the next commit is written in a single transaction.
+        self.client.batch_execute("BEGIN").await?;
+        self.upsert_block(block_number, block_hash, timestamp).await
+    }
+
+    async fn insert_event(&self, meta: &LogMeta, event: &USDC_WETH_POOLEvents) -> Result<()> {
+        self.savepoint(async {
+            let id = Some(self.insert_transaction_logs(meta).await?);
+            let block_number = Some(meta.block_number.as_u64());
+
+            let event_id = match event {
+                USDC_WETH_POOLEvents::SwapFilter(f) => {
+                    self.insert_swap_event(id, block_number, f.clone()).await?
+                }
+                USDC_WETH_POOLEvents::BurnFilter(f) => {
+                    self.insert_burn_event(id, block_number, f.clone()).await?
+                }
+                USDC_WETH_POOLEvents::MintFilter(f) => {
+                    self.insert_mint_event(id, block_number, f.clone()).await?
+                }
+                USDC_WETH_POOLEvents::FlashFilter(f) => {
+                    self.insert_flash_event(id, block_number, f.clone()).await?
+                }
+                _ => return Ok(()),
+            };
+
+            // Delivered to listeners once the block commits
+            if self.notifications {
+                if let Some(notification) = Notification::new(meta, event, event_id) {
+                    self.notify(&notification).await?;
+                }
+            }
+            self.apply_rollups(meta.address, event, meta.block_number.as_u64(), 1)
+                .await?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn insert_raw_log(&self, meta: &LogMeta, log: &RawLog) -> Result<()> {
+        let address: Address = meta.address.into();
+        let topic = |i: usize| log.topics.get(i).map(|t| t.as_bytes());
+
+        self.savepoint(async {
+            self.client
+                .execute(
+                    "
+                   INSERT INTO raw_logs (
+                       block_number,
+                       block_hash,
+                       transaction_hash,
+                       transaction_index,
+                       log_index,
+                       address,
+                       topic0,
+                       topic1,
+                       topic2,
+                       topic3,
+                       data
+                   )
+                   VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                   ",
+                    &[
+                        &&meta.block_number.as_u64().to_be_bytes()[..],
+                        &meta.block_hash.as_bytes(),
+                        &meta.transaction_hash.as_bytes(),
+                        &(meta.transaction_index.as_u64() as i64),
+                        &(meta.log_index.as_u64() as i64),
+                        &address,
+                        &topic(0),
+                        &topic(1),
+                        &topic(2),
+                        &topic(3),
+                        &log.data,
+                    ],
+                )
+                .await?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn insert_dead_letter(
+        &self,
+        meta: &LogMeta,
+        log: Option<&RawLog>,
+        error: &str,
+    ) -> Result<()> {
+        let address: Address = meta.address.into();
+        let topic = |i: usize| log.and_then(|l| l.topics.get(i)).map(|t| t.as_bytes());
 
         self.client
             .execute(
                 "
-               INSERT INTO flash_logs (
-                   sender_address,
-                   receiver_address,
-                   amount0,
-                   amount1,
-                   paid0,
-                   paid1
+               INSERT INTO dead_letters (
+                   block_number,
+                   block_hash,
+                   transaction_hash,
+                   transaction_index,
+                   log_index,
+                   address,
+                   topic0,
+                   topic1,
+                   topic2,
+                   topic3,
+                   data,
+                   error
                )
-               VALUES ($1, $2, $3, $4, $5, $6)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
                ",
-                &[&sender, &rec, &amount0, &amount1, &paid0, &paid1],
+                &[
+                    &&meta.block_number.as_u64().to_be_bytes()[..],
+                    &meta.block_hash.as_bytes(),
+                    &meta.transaction_hash.as_bytes(),
+                    &(meta.transaction_index.as_u64() as i64),
+                    &(meta.log_index.as_u64() as i64),
+                    &address,
+                    &topic(0),
+                    &topic(1),
+                    &topic(2),
+                    &topic(3),
+                    &log.map(|l| &l.data),
+                    &error,
+                ],
             )
             .await?;
 
-        // Log a message indicating the insertion of the Flash event.
-        info!("Inserting Flash event: {:?}", events);
+        Ok(())
+    }
+
+    async fn commit(&self) -> Result<()> {
+        self.commit_transaction().await
+    }
+
+    async fn rollback_to_block(&self, block_number: u64) -> Result<()> {
+        // The rollups are reversed in the same transaction as the rows they count, so an
+        // interrupted rollback leaves both untouched.
+        self.client.batch_execute("BEGIN").await?;
+
+        match self.delete_above(block_number).await {
+            Ok(()) => self.commit_transaction().await?,
+            Err(e) => {
+                self.rollback_transaction().await?;
+                return Err(e);
+            }
+        }
+
+        info!("Rolled back to block {}", block_number);
 
         Ok(())
     }
+
+    async fn cover_blocks(&self, from: u64, to: u64) -> Result<()> {
+        self.cover_range(from, to).await
+    }
+
+    async fn checkpoint(&self) -> Result<Option<u64>> {
+        let row = self
+            .client
+            .query_one("SELECT MAX(number) AS number FROM blocks", &[])
+            .await?;
+
+        Ok(row.get::<_, Option<i64>>("number").map(|n| n as u64))
+    }
+
+    async fn block_hash(&self, block_number: u64) -> Result<Option<H256>> {
+        let row = self
+            .client
+            .query_opt(
+                "SELECT hash FROM blocks WHERE number = $1",
+                &[&(block_number as i64)],
+            )
+            .await?;
+
+        Ok(row
+            .map(|row| fixed::<32>("block hash", row.get("hash")))
+            .transpose()?
+            .map(H256))
+    }
+}
+
+/// The partitions known to a connection, so that blocks do not query the catalog.
+///
+/// Like the address ids, partitions created by a transaction are pending until it commits, so
+/// a rolled back partition is looked up again.
+#[derive(Debug, Default)]
+struct PartitionCache {
+    /// The partition size once read, `Some(None)` if the tables are not partitioned.
+    size: Option<Option<u64>>,
+
+    /// The first blocks of the partitions known to exist.
+    committed: HashSet<u64>,
+
+    /// The first blocks of the partitions seen or created by the open transaction.
+    pending: HashSet<u64>,
+}
+
+impl PartitionCache {
+    /// Returns `true` if the partition starting at `start` is known to exist.
+    fn contains(&self, start: u64) -> bool {
+        self.committed.contains(&start) || self.pending.contains(&start)
+    }
+
+    /// Keeps a partition seen or created by the open transaction.
+    fn insert(&mut self, start: u64) {
+        self.pending.insert(start);
+    }
+
+    /// Forgets a detached partition.
+    fn remove(&mut self, start: u64) {
+        self.committed.remove(&start);
+        self.pending.remove(&start);
+    }
+
+    /// Moves the pending partitions into the committed ones, once their transaction commits.
+    fn commit(&mut self) {
+        self.committed.extend(self.pending.drain());
+    }
+
+    /// Forgets the pending partitions of a rolled back transaction.
+    fn forget(&mut self) {
+        self.pending.clear();
+    }
+}
+
+/// The tables partitioned by block range, in the order partitions are created.
+const PARTITIONED_TABLES: [&str; 6] = [
+    "ethereum_logs",
+    "swap_logs",
+    "burn_logs",
+    "mint_logs",
+    "flash_logs",
+    "raw_logs",
+];
+
+/// The table partitioned last, whose partition existing means every table has it.
+const LAST_PARTITIONED_TABLE: &str = PARTITIONED_TABLES[PARTITIONED_TABLES.len() - 1];
+
+/// The name of the partition of `table` starting at block `start`.
+pub fn partition_name(table: &str, start: u64) -> String {
+    format!("{table}_p{start:012}")
+}
+
+/// A bytea literal of a block number, encoded like the `block_number` columns.
+pub fn bytea_literal(block_number: u64) -> String {
+    format!("'\\x{block_number:016x}'::bytea")
 }
@@ -0,0 +1,361 @@
+use ethers::{
+    abi::RawLog,
+    prelude::LogMeta,
+    types::{H256, U256, U64},
+};
+use eyre::{eyre, Result};
+use log::{info, warn};
+use rusqlite::params;
+use std::fmt;
+
+use crate::database::DB;
+use crate::decode::decode_log;
+use crate::redecode::{row_meta, row_raw_log};
+use crate::storage::{sqlite::SqliteStorage, Storage};
+use crate::types::Address;
+
+/// The number of dead letters read at once by `retry_dead_letters`.
+const RETRY_BATCH: i64 = 100;
+
+/// A log that failed to decode or to be written, kept for a later retry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadLetter {
+    /// The id of the `dead_letters` row.
+    pub id: i32,
+
+    /// The log metadata of the failed log.
+    pub meta: LogMeta,
+
+    /// The topics and data of the log, if they were known.
+    pub log: Option<RawLog>,
+
+    /// The error of the last attempt.
+    pub error: String,
+
+    /// The number of failed retries.
+    pub retries: i32,
+}
+
+impl fmt::Display for DeadLetter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} block {} tx {:?} log {} retries {}: {}",
+            self.id,
+            self.meta.block_number,
+            self.meta.transaction_hash,
+            self.meta.log_index,
+            self.retries,
+            self.error
+        )
+    }
+}
+
+impl DB {
+    /// Reads the oldest dead letters.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit`: The maximum number of dead letters to read.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the dead letters, ordered by id.
+    pub async fn dead_letters(&self, limit: u32) -> Result<Vec<DeadLetter>> {
+        self.read_dead_letters(None, 0, limit.into()).await
+    }
+
+    /// Reads dead letters in id order.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: Only read this dead letter, if set.
+    /// - `after`: Only read dead letters with a greater id.
+    /// - `limit`: The maximum number of dead letters to read.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the dead letters, ordered by id.
+    async fn read_dead_letters(
+        &self,
+        id: Option<i32>,
+        after: i32,
+        limit: i64,
+    ) -> Result<Vec<DeadLetter>> {
+        let rows = self
+            .client
+            .query(
+                "
+               SELECT * FROM dead_letters
+               WHERE id > $1 AND ($2::INT4 IS NULL OR id = $2)
+               ORDER BY id
+               LIMIT $3
+               ",
+                &[&after, &id, &limit],
+            )
+            .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(DeadLetter {
+                    id: row.get("id"),
+                    meta: row_meta(row)?,
+                    log: row_raw_log(row)?,
+                    error: row.get("error"),
+                    retries: row.get("retries"),
+                })
+            })
+            .collect()
+    }
+
+    /// Decodes and writes dead letters again.
+    ///
+    /// Each dead letter is retried in its own transaction. It is deleted once written, and
+    /// otherwise keeps the new error with its retry count incremented.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: The dead letter to retry, every one by default.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of written and still failing dead letters.
+    pub async fn retry_dead_letters(&self, id: Option<i32>) -> Result<(u64, u64)> {
+        let (mut written, mut failed) = (0, 0);
+        let mut after = 0;
+
+        loop {
+            let letters = self.read_dead_letters(id, after, RETRY_BATCH).await?;
+            let Some(last) = letters.last() else { break };
+            after = last.id;
+
+            for letter in &letters {
+                self.client.batch_execute("BEGIN").await?;
+
+                let result = async {
+                    let log = letter
+                        .log
+                        .as_ref()
+                        .ok_or_else(|| eyre!("the raw log is unknown"))?;
+                    let event = decode_log(log)?;
+                    self.insert_event(&letter.meta, &event).await?;
+
+                    self.client
+                        .execute("DELETE FROM dead_letters WHERE id = $1", &[&letter.id])
+                        .await?;
+
+                    Ok::<_, eyre::Report>(())
+                }
+                .await;
+
+                match result {
+                    Ok(()) => {
+                        self.commit_transaction().await?;
+                        written += 1;
+                    }
+                    Err(e) => {
+                        self.rollback_transaction().await?;
+                        warn!("Dead letter {} failed again: {}", letter.id, e);
+
+                        self.client
+                            .execute(
+                                "
+                           UPDATE dead_letters
+                           SET retries = retries + 1, error = $2
+                           WHERE id = $1
+                           ",
+                                &[&letter.id, &e.to_string()],
+                            )
+                            .await?;
+                        failed += 1;
+                    }
+                }
+            }
+        }
+
+        info!(
+            "Retried dead letters, {} written, {} failed",
+            written, failed
+        );
+
+        Ok((written, failed))
+    }
+}
+
+impl SqliteStorage {
+    /// Reads the oldest dead letters.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit`: The maximum number of dead letters to read.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the dead letters, ordered by id.
+    pub async fn dead_letters(&self, limit: u32) -> Result<Vec<DeadLetter>> {
+        self.read_dead_letters(None, 0, limit.into()).await
+    }
+
+    /// Reads dead letters in id order.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: Only read this dead letter, if set.
+    /// - `after`: Only read dead letters with a greater id.
+    /// - `limit`: The maximum number of dead letters to read.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the dead letters, ordered by id.
+    async fn read_dead_letters(
+        &self,
+        id: Option<i32>,
+        after: i32,
+        limit: i64,
+    ) -> Result<Vec<DeadLetter>> {
+        self.blocking(move |conn| {
+            let mut statement = conn.prepare(
+                "
+                SELECT * FROM dead_letters
+                WHERE id > ?1 AND (?2 IS NULL OR id = ?2)
+                ORDER BY id
+                LIMIT ?3
+                ",
+            )?;
+            let mut rows = statement.query(params![after, id, limit])?;
+
+            let mut letters = Vec::new();
+            while let Some(row) = rows.next()? {
+                letters.push(sqlite_dead_letter(row)?);
+            }
+
+            Ok(letters)
+        })
+        .await
+    }
+
+    /// Decodes and writes dead letters again.
+    ///
+    /// Each dead letter is retried in its own transaction. It is deleted once written, and
+    /// otherwise keeps the new error with its retry count incremented.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: The dead letter to retry, every one by default.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of written and still failing dead letters.
+    pub async fn retry_dead_letters(&self, id: Option<i32>) -> Result<(u64, u64)> {
+        let (mut written, mut failed) = (0, 0);
+        let mut after = 0;
+
+        loop {
+            let letters = self.read_dead_letters(id, after, RETRY_BATCH).await?;
+            let Some(last) = letters.last() else { break };
+            after = last.id;
+
+            for letter in &letters {
+                let letter_id = letter.id;
+                self.blocking(|conn| Ok(conn.execute_batch("BEGIN")?))
+                    .await?;
+
+                let result = async {
+                    let log = letter
+                        .log
+                        .as_ref()
+                        .ok_or_else(|| eyre!("the raw log is unknown"))?;
+                    let event = decode_log(log)?;
+                    self.insert_event(&letter.meta, &event).await?;
+
+                    self.blocking(move |conn| {
+                        conn.execute("DELETE FROM dead_letters WHERE id = ?1", params![letter_id])?;
+                        Ok(())
+                    })
+                    .await
+                }
+                .await;
+
+                match result {
+                    Ok(()) => {
+                        self.commit().await?;
+                        written += 1;
+                    }
+                    Err(e) => {
+                        warn!("Dead letter {} failed again: {}", letter.id, e);
+
+                        let error = e.to_string();
+                        self.blocking(move |conn| {
+                            conn.execute_batch("ROLLBACK")?;
+                            conn.execute(
+                                "
+                                UPDATE dead_letters
+                                SET retries = retries + 1, error = ?2
+                                WHERE id = ?1
+                                ",
+                                params![letter_id, error],
+                            )?;
+                            Ok(())
+                        })
+                        .await?;
+                        failed += 1;
+                    }
+                }
+            }
+        }
+
+        info!(
+            "Retried dead letters, {} written, {} failed",
+            written, failed
+        );
+
+        Ok((written, failed))
+    }
+}
+
+/// Reads a `dead_letters` row of the SQLite backend.
+fn sqlite_dead_letter(row: &rusqlite::Row) -> Result<DeadLetter> {
+    let block_number: Vec<u8> = row.get("block_number")?;
+    let block_number = u64::from_be_bytes(
+        block_number
+            .try_into()
+            .map_err(|_| eyre!("invalid block number"))?,
+    );
+
+    let hash = |column: &str| -> Result<H256> {
+        let raw: Vec<u8> = row.get(column)?;
+        match raw.len() {
+            32 => Ok(H256::from_slice(&raw)),
+            _ => Err(eyre!("invalid {}", column)),
+        }
+    };
+
+    let log = match row.get::<_, Option<Vec<u8>>>("data")? {
+        Some(data) => {
+            let mut topics = Vec::new();
+            for column in ["topic0", "topic1", "topic2", "topic3"] {
+                if row.get::<_, Option<Vec<u8>>>(column)?.is_none() {
+                    break;
+                }
+                topics.push(hash(column)?);
+            }
+            Some(RawLog { topics, data })
+        }
+        None => None,
+    };
+
+    Ok(DeadLetter {
+        id: row.get("id")?,
+        meta: LogMeta {
+            address: *row.get::<_, Address>("address")?,
+            block_number: U64::from(block_number),
+            block_hash: hash("block_hash")?,
+            transaction_hash: hash("transaction_hash")?,
+            transaction_index: U64::from(row.get::<_, i64>("transaction_index")? as u64),
+            log_index: U256::from(row.get::<_, i64>("log_index")? as u64),
+        },
+        log,
+        error: row.get("error")?,
+        retries: row.get("retries")?,
+    })
+}
@@ -1,24 +1,37 @@
 use env_logger::Builder;
 use ethers::{
+    abi::RawLog,
+    contract::EthLogDecode,
+    prelude::LogMeta,
     providers::{Provider, StreamExt, Ws},
-    types::Address,
+    types::{Log, H256},
 };
 use eyre::Result;
-use std::sync::Arc;
+use futures::{future, stream, Stream};
+use log::{info, warn};
+use tokio::signal;
 
-use crate::constants::URL;
+use crate::config::{Backend, Settings};
+use crate::coverage;
 use crate::database::DB;
+use crate::dynamic;
+use crate::error::{is_transient, IndexerError};
+use crate::ingest::{BlockTimes, Source};
+use crate::retention;
+use crate::storage::{parquet::ParquetSink, sqlite::SqliteStorage, tee::Tee, Storage};
 use crate::types::pool_types::usdc_weth_pool::*;
 
 /// Initializes the Ethereum event log indexer.
 ///
 /// This function performs the following tasks:
 ///
-/// 1. Sets up the logger with a specified log level.
-/// 2. Establishes a WebSocket connection to an Ethereum node.
-/// 3. Initializes a smart contract instance for event retrieval.
-/// 4. Initializes a PostgreSQL database connection.
-/// 5. Starts the event log decoding and indexing process.
+/// 1. Sets up the logger with a specified log level, and hands over to `dynamic::initialise`
+///    when contracts are configured by ABI.
+/// 2. Establishes a WebSocket connection to the configured Ethereum node.
+/// 3. Initializes the configured storage backend, PostgreSQL or SQLite, the optional
+///    Parquet sink and the optional background pruning and backfilling of PostgreSQL tables.
+/// 4. Starts the event log decoding and indexing process, until it fails or a shutdown signal
+///    arrives, and closes the storage either way so the Parquet sink writes its buffered blocks.
 ///
 /// # Returns
 ///
@@ -30,56 +43,393 @@ pub async fn initialise() -> Result<()> {
         .parse_env("ETH_LOG")
         .init();
 
+    // Contracts configured by ABI are indexed instead of the pool
+    let settings = Settings::new()?;
+    settings.validate_rpc()?;
+    if settings.by_abi() {
+        return dynamic::initialise(settings).await;
+    }
+
     // Setup WebSocket
-    let address: Address = "0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640".parse()?;
-    let provider = Arc::new(Provider::<Ws>::connect(URL).await?);
-    let contract = USDC_WETH_POOL::new(address, provider);
+    let source = Source::pool(&settings)?;
+    let provider = source.connect().await?;
+
+    // Initialize the storage backend and run the event indexer
+    let storage: Box<dyn Storage> = match settings.database.backend {
+        Backend::Postgres => {
+            let db = DB::new().await?.with_notifications();
+            if let Some(partitioning) = &settings.database.partitioning {
+                db.create_partitioned_table(partitioning.blocks_per_partition)
+                    .await?;
+            }
+            Box::new(db)
+        }
+        Backend::Sqlite => Box::new(SqliteStorage::open(&settings.database.path)?),
+    };
+
+    // Mirror the backend into Parquet files when the sink is enabled
+    let storage: Box<dyn Storage> = match settings.parquet {
+        Some(parquet) => {
+            let sink =
+                ParquetSink::open(&parquet.dir, parquet.blocks_per_file, parquet.reorg_depth)?;
+            Box::new(Tee::new(storage, sink))
+        }
+        None => storage,
+    };
+
+    storage.create_table().await?;
+
+    // Prune old rows in the background once the tables exist
+    if let Some(retention) = settings.retention {
+        retention::spawn(retention)?;
+    }
+
+    // Fill the block ranges missed between runs in the background
+    if let Some(backfill) = settings.backfill {
+        coverage::spawn(backfill, source.clone())?;
+    }
 
-    // Initialize database connection
-    let db = DB::new().await?;
-    db.create_table().await?;
+    // The sinks are flushed on every exit, including errors and shutdown signals
+    let indexing = decode_events(&source, &provider, &storage, settings.database.raw_logs);
+    let indexed = tokio::select! {
+        indexed = indexing => indexed,
+        signal = shutdown() => signal,
+    };
+    let closed = storage.close().await;
 
-    // Run the event indexer
-    decode_events(&contract, &db).await?;
+    indexed.and(closed)
+}
+
+/// Waits for Ctrl-C, or for SIGTERM on Unix.
+async fn shutdown() -> Result<()> {
+    #[cfg(unix)]
+    {
+        let mut terminate = signal::unix::signal(signal::unix::SignalKind::terminate())?;
+        tokio::select! {
+            interrupted = signal::ctrl_c() => interrupted?,
+            _ = terminate.recv() => {}
+        }
+    }
+    #[cfg(not(unix))]
+    signal::ctrl_c().await?;
 
+    info!("Shutting down");
     Ok(())
 }
 
 /// Decodes Ethereum event logs and indexes them in the database.
 ///
-/// This function continuously listens for Ethereum event logs from a smart contract using a
-/// WebSocket connection. It decodes various event types and inserts them into a PostgreSQL database.
+/// This function follows the logs of the pools from the node, from their start block when
+/// one is configured, and hands them to `index_logs` to be decoded and indexed. Once a batch
+/// is indexed, its whole block range is recorded as processed.
 ///
 /// # Arguments
 ///
-/// - `contract`: A reference to the USDC_WETH_POOL smart contract instance.
-/// - `db`: A reference to the PostgreSQL database instance.
+/// - `source`: The logs of the pools.
+/// - `provider`: The node the logs are read from.
+/// - `storage`: A reference to the storage backend the events are written to.
+/// - `raw_logs`: Whether to also write the topics and data of every log.
 ///
 /// # Returns
 ///
 /// Returns a `Result` indicating the success or failure of the event decoding and indexing process.
-async fn decode_events(contract: &USDC_WETH_POOL<Provider<Ws>>, db: &DB) -> Result<()> {
-    // Retrieve the contract's events
-    let events = contract.events();
-
-    // Create a stream of events with metadata
-    let mut stream = events.stream().await?.with_meta();
-
-    while let Some(Ok((event, meta))) = stream.next().await {
-        // Insert transaction logs into the database
-        db.insert_transaction_logs(meta).await?;
-
-        // Match and insert specific event types into the database
-        match event {
-            USDC_WETH_POOLEvents::SwapFilter(f) => db.insert_swap_event(f).await?,
-            USDC_WETH_POOLEvents::BurnFilter(f) => db.insert_burn_event(f).await?,
-            USDC_WETH_POOLEvents::MintFilter(f) => db.insert_mint_event(f).await?,
-            USDC_WETH_POOLEvents::FlashFilter(f) => db.insert_flash_event(f).await?,
-            _ => {}
+pub async fn decode_events<S: Storage + ?Sized>(
+    source: &Source,
+    provider: &Provider<Ws>,
+    storage: &S,
+    raw_logs: bool,
+) -> Result<()> {
+    // Follow the undecoded logs, so a log failing to decode can still be kept
+    let follow = source.follow(provider, storage.checkpoint().await?).await?;
+
+    // Each batch covers whole blocks, and is committed before the next one
+    let mut batches = follow.batches;
+    while let Some(batch) = batches.next().await {
+        let batch = batch?;
+        let logs = stream::iter(batch.logs.into_iter().map(Ok::<_, eyre::Report>));
+        index_logs(logs, storage, raw_logs, provider).await?;
+
+        // The blocks of the range without logs were processed too
+        storage.cover_blocks(batch.from, batch.to).await?;
+    }
+
+    match follow.live {
+        Some(live) => index_logs(live, storage, raw_logs, provider).await,
+        None => Ok(()),
+    }
+}
+
+/// Writes a stream of decoded events into a storage backend.
+///
+/// Events are grouped by block. Each block is committed once an event of a later block arrives,
+/// or when the stream ends. A block at or below the checkpoint whose hash differs from the
+/// stored one is treated as a reorg, and the storage is rolled back to the block before it
+/// first, while the events of a block stored with the same hash are skipped. Every block
+/// between two committed blocks of the same run is recorded as processed, so the blocks missed
+/// between runs show up as gaps. An error of the stream stops indexing and is returned.
+///
+/// # Arguments
+///
+/// - `stream`: A stream of decoded events together with their log metadata.
+/// - `storage`: A reference to the storage backend the events are written to.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the indexing process.
+pub async fn index_events<St, E, S>(stream: St, storage: &S) -> Result<()>
+where
+    St: Stream<Item = Result<(USDC_WETH_POOLEvents, LogMeta), E>> + Unpin,
+    E: Into<eyre::Report>,
+    S: Storage + ?Sized,
+{
+    let stream = stream.map(|item| item.map(|(event, meta)| (meta, Ok(event), None)));
+    index(stream, storage, false, &()).await
+}
+
+/// Decodes a stream of raw logs and writes them into a storage backend.
+///
+/// Logs are decoded and indexed like `index_events`. A log that fails to decode is kept as a
+/// dead letter, and when `keep_raw` is set, the topics and data of every log are also written
+/// with `insert_raw_log`. The timestamp of every block is read from `times`.
+///
+/// # Arguments
+///
+/// - `stream`: A stream of logs of the pool.
+/// - `storage`: A reference to the storage backend the logs are written to.
+/// - `keep_raw`: Whether to write the topics and data of every log.
+/// - `times`: The timestamps of the blocks, usually the node.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the indexing process.
+pub async fn index_logs<St, E, S>(
+    stream: St,
+    storage: &S,
+    keep_raw: bool,
+    times: &dyn BlockTimes,
+) -> Result<()>
+where
+    St: Stream<Item = Result<Log, E>> + Unpin,
+    E: Into<eyre::Report>,
+    S: Storage + ?Sized,
+{
+    let stream = stream.filter_map(|item| {
+        future::ready(
+            item.map(|log| {
+                // Pending logs have no position yet
+                let Some(meta) = log_meta(&log) else {
+                    warn!("Skipping pending log {:?}", log.transaction_hash);
+                    return None;
+                };
+
+                let raw = RawLog::from(log);
+                let event = decode_log(&raw).map_err(|e| format!("failed to decode log: {e}"));
+
+                Some((meta, event, Some(raw)))
+            })
+            .transpose(),
+        )
+    });
+
+    index(stream, storage, keep_raw, times).await
+}
+
+/// Decodes the topics and data of a pool log.
+///
+/// # Arguments
+///
+/// - `log`: The topics and data of the log.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the decoded event, or an error if the log does not match the
+/// pool ABI.
+pub fn decode_log(log: &RawLog) -> Result<USDC_WETH_POOLEvents, IndexerError> {
+    Ok(<USDC_WETH_POOLEvents as EthLogDecode>::decode_log(log)?)
+}
+
+/// Reads the position of a mined log, or `None` for a pending one.
+pub(crate) fn log_meta(log: &Log) -> Option<LogMeta> {
+    Some(LogMeta {
+        address: log.address,
+        block_number: log.block_number?,
+        block_hash: log.block_hash?,
+        transaction_hash: log.transaction_hash?,
+        transaction_index: log.transaction_index?,
+        log_index: log.log_index?,
+    })
+}
+
+/// A log to index, with its decoded event or decoding error, and its raw log if known.
+type Item = (
+    LogMeta,
+    Result<USDC_WETH_POOLEvents, String>,
+    Option<RawLog>,
+);
+
+/// The indexing loop shared by `index_events` and `index_logs`.
+///
+/// A log that fails to decode or to be written goes to the dead letters of the storage, and
+/// indexing moves on to the next one.
+async fn index<St, E, S>(
+    mut stream: St,
+    storage: &S,
+    keep_raw: bool,
+    times: &dyn BlockTimes,
+) -> Result<()>
+where
+    St: Stream<Item = Result<Item, E>> + Unpin,
+    E: Into<eyre::Report>,
+    S: Storage + ?Sized,
+{
+    let mut blocks = Blocks::resume(storage, times).await?;
+
+    while let Some(item) = stream.next().await {
+        let (meta, event, raw) = item.map_err(Into::into)?;
+        if !blocks.open(storage, &meta).await? {
+            continue;
+        }
+
+        // Transient write errors stop the run, so the uncommitted block is written again on
+        // restart instead of becoming dead letters
+        if let Some(raw) = raw.as_ref().filter(|_| keep_raw) {
+            match storage.insert_raw_log(&meta, raw).await {
+                Err(e) if is_transient(&e) => return Err(e),
+                Err(e) => {
+                    dead_letter(storage, &meta, Some(raw), &e.to_string()).await?;
+                    continue;
+                }
+                Ok(()) => {}
+            }
+        }
+
+        // Insert the event and its transaction log
+        let written = match &event {
+            Ok(event) => match storage.insert_event(&meta, event).await {
+                Err(e) if is_transient(&e) => return Err(e),
+                result => result.map_err(|e| e.to_string()),
+            },
+            Err(e) => Err(e.clone()),
+        };
+
+        if let Err(e) = written {
+            dead_letter(storage, &meta, raw.as_ref(), &e).await?;
         }
     }
 
+    blocks.close(storage).await
+}
 
-    Ok(())
+/// The blocks of an indexing run, opened one at a time in the order of the stream.
+pub(crate) struct Blocks<'a> {
+    /// The timestamps of the blocks.
+    times: &'a dyn BlockTimes,
+
+    /// The last committed block.
+    checkpoint: Option<u64>,
+
+    /// The number and hash of the open block.
+    current: Option<(u64, H256)>,
+
+    /// The last block committed by this run, anything before it is unknown.
+    covered_to: Option<u64>,
 }
 
+impl<'a> Blocks<'a> {
+    /// Starts a run from the checkpoint of a storage backend.
+    pub(crate) async fn resume<S: Storage + ?Sized>(
+        storage: &S,
+        times: &'a dyn BlockTimes,
+    ) -> Result<Self> {
+        Ok(Self {
+            times,
+            checkpoint: storage.checkpoint().await?,
+            current: None,
+            covered_to: None,
+        })
+    }
+
+    /// Opens the block of a log, unless it is already open.
+    ///
+    /// A block at or below the checkpoint is compared with the stored block of the same number.
+    /// The same hash means the block is replayed, and its logs are skipped. Otherwise the open
+    /// block is committed first, and a block at or below the checkpoint is treated as a reorg,
+    /// rolling the storage back to the block before it. Every block since the last committed one
+    /// is recorded as processed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing whether the log should be indexed.
+    pub(crate) async fn open<S: Storage + ?Sized>(
+        &mut self,
+        storage: &S,
+        meta: &LogMeta,
+    ) -> Result<bool> {
+        let block_number = meta.block_number.as_u64();
+        if self.current == Some((block_number, meta.block_hash)) {
+            return Ok(true);
+        }
+
+        // A block committed with the same hash is delivered again, not reorged
+        if self.checkpoint.is_some_and(|c| block_number <= c)
+            && self.current.is_none_or(|(n, _)| n != block_number)
+            && storage.block_hash(block_number).await? == Some(meta.block_hash)
+        {
+            return Ok(false);
+        }
+
+        // Commit the previous block before starting the next one
+        if let Some((block, _)) = self.current {
+            storage.commit().await?;
+            self.checkpoint = Some(block);
+            self.covered_to = Some(block);
+        }
+
+        // Drop anything we already stored for a block that is being replayed
+        if self.checkpoint.is_some_and(|c| block_number <= c) {
+            warn!("Reorg detected at block {}", block_number);
+            storage
+                .rollback_to_block(block_number.saturating_sub(1))
+                .await?;
+            self.checkpoint = storage.checkpoint().await?;
+            self.covered_to = self
+                .covered_to
+                .map(|c| c.min(block_number.saturating_sub(1)));
+        }
+
+        let timestamp = self.times.block_time(meta.block_hash).await?;
+        storage
+            .insert_block(block_number, meta.block_hash, timestamp)
+            .await?;
+
+        // The stream had no events for the blocks since the last committed one
+        let from = self.covered_to.map_or(block_number, |c| c + 1);
+        storage.cover_blocks(from, block_number).await?;
+        self.current = Some((block_number, meta.block_hash));
+
+        Ok(true)
+    }
+
+    /// Commits the open block, if any, at the end of the stream.
+    pub(crate) async fn close<S: Storage + ?Sized>(&self, storage: &S) -> Result<()> {
+        if self.current.is_some() {
+            storage.commit().await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Keeps a failed log as a dead letter, failing only if the dead letter cannot be written.
+pub(crate) async fn dead_letter<S: Storage + ?Sized>(
+    storage: &S,
+    meta: &LogMeta,
+    raw: Option<&RawLog>,
+    error: &str,
+) -> Result<()> {
+    warn!(
+        "Dead letter for block {} log {}: {}",
+        meta.block_number, meta.log_index, error
+    );
+    storage.insert_dead_letter(meta, raw, error).await
+}
@@ -0,0 +1,568 @@
+use ethers::{
+    abi::{Abi, Event, EventParam, Log as DecodedLog, RawLog, Token},
+    prelude::LogMeta,
+    providers::StreamExt,
+    types::{Log, H160, H256, I256},
+    utils::{hex, to_checksum},
+};
+use eyre::{eyre, Result};
+use futures::{stream, Stream};
+use log::{info, warn};
+use std::collections::HashSet;
+
+use crate::addresses::address_view;
+use crate::config::{ContractSettings, Settings};
+use crate::database::DB;
+use crate::decode::{dead_letter, log_meta, Blocks};
+use crate::error::{is_transient, IndexerError};
+use crate::generate::{ContractAbi, POOL_CONTRACT};
+use crate::indexed::{event_table, ColumnKind, Value, SCHEMAS};
+use crate::ingest::{BlockTimes, Source};
+use crate::retention;
+use crate::storage::Storage;
+use crate::types::{Wu128, WI256, WU256};
+
+/// The columns every event table starts or ends with.
+const RESERVED_COLUMNS: [&str; 4] = ["id", "ethereum_log_id", "block_number", "timestamp"];
+
+/// A contract indexed from its ABI at runtime.
+#[derive(Debug, Clone)]
+pub struct DynamicContract {
+    /// The name of the contract, prefixing its event tables.
+    pub name: String,
+
+    /// The addresses of the deployed contracts.
+    pub addresses: Vec<H160>,
+
+    /// The events of the ABI, each with its own table.
+    pub events: Vec<DynamicEvent>,
+}
+
+/// An event of an ABI and the table it is stored in.
+#[derive(Debug, Clone)]
+pub struct DynamicEvent {
+    /// The ABI of the event.
+    pub event: Event,
+
+    /// The table of the event, e.g. `erc20_transfer_logs`.
+    pub table: String,
+
+    /// The columns of the event inputs, in the order of the inputs.
+    pub columns: Vec<DynamicColumn>,
+}
+
+/// A column of an event input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicColumn {
+    /// The name of the column, or of the address role for addresses.
+    pub name: String,
+
+    /// How the column is stored.
+    pub kind: ColumnKind,
+}
+
+impl DynamicColumn {
+    /// The quoted name of the column in the table, `<name>_id` for addresses.
+    pub fn stored_name(&self) -> String {
+        match self.kind {
+            ColumnKind::Address => format!("\"{}_id\"", self.name),
+            _ => format!("\"{}\"", self.name),
+        }
+    }
+}
+
+impl DynamicContract {
+    /// Loads a configured contract and its ABI, keeping only its allowed events.
+    ///
+    /// # Arguments
+    ///
+    /// - `settings`: The settings of the contract.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the contract, or an error if its ABI or address is
+    /// invalid.
+    pub fn load(settings: &ContractSettings) -> Result<Self> {
+        let mut contract =
+            Self::from_abi(&settings.name, &settings.abi()?, vec![settings.address()?])?;
+
+        if !settings.events.is_empty() {
+            contract
+                .events
+                .retain(|e| settings.events.contains(&e.event.name));
+        }
+
+        Ok(contract)
+    }
+
+    /// Creates a contract from its ABI, deriving a table for each event.
+    ///
+    /// Tables are named `<contract>_<event>_logs` in snake case, with a numeric suffix for
+    /// overloaded events. Anonymous events have no selector to match logs with and are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: The name of the contract.
+    /// - `abi`: The ABI of the contract.
+    /// - `addresses`: The addresses of the deployed contracts.
+    pub fn from_abi(name: &str, abi: &Abi, addresses: Vec<H160>) -> Result<Self> {
+        let prefix = identifier(name).ok_or_else(|| eyre!("invalid contract name {name}"))?;
+
+        let mut events = Vec::new();
+        for overloads in abi.events.values() {
+            for (i, event) in overloads.iter().enumerate() {
+                if event.anonymous {
+                    warn!("Skipping anonymous event {}", event.name);
+                    continue;
+                }
+
+                let mut table = format!(
+                    "{prefix}_{}",
+                    identifier(&event.name).unwrap_or_else(|| "event".to_string())
+                );
+                if i > 0 {
+                    table += &format!("_{}", i + 1);
+                }
+
+                events.push(DynamicEvent {
+                    event: event.clone(),
+                    table: format!("{table}_logs"),
+                    columns: columns(&event.inputs),
+                });
+            }
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            addresses,
+            events,
+        })
+    }
+
+    /// Finds the event of a log by its selector.
+    pub fn event(&self, selector: H256) -> Option<&DynamicEvent> {
+        self.events.iter().find(|e| e.event.signature() == selector)
+    }
+}
+
+impl DynamicEvent {
+    /// The view presenting the table with its addresses, e.g. `erc20_transfer_events`.
+    pub fn view(&self) -> String {
+        format!("{}_events", self.table.trim_end_matches("_logs"))
+    }
+
+    /// Returns the statements creating the table, its indexes and its view, if they do not
+    /// already exist.
+    ///
+    /// Besides the `ethereum_logs` foreign key, every input indexed in the topics of the event
+    /// is indexed in the table.
+    pub fn create_table(&self) -> String {
+        let definitions: Vec<String> = self
+            .columns
+            .iter()
+            .map(|c| format!("{} {}", c.stored_name(), c.kind.column_type()))
+            .collect();
+        let roles: Vec<&str> = self
+            .columns
+            .iter()
+            .filter(|c| c.kind == ColumnKind::Address)
+            .map(|c| c.name.as_str())
+            .collect();
+
+        let table = &self.table;
+        let mut indexes = format!(
+            "
+            CREATE INDEX IF NOT EXISTS {table}_ethereum_log_id_idx ON {table} (ethereum_log_id);"
+        );
+        for (column, input) in self.columns.iter().zip(&self.event.inputs) {
+            if input.indexed {
+                indexes += &format!(
+                    "
+            CREATE INDEX IF NOT EXISTS \"{table}_{}_idx\" ON {table} ({});",
+                    column.name,
+                    column.stored_name()
+                );
+            }
+        }
+
+        event_table(table, &definitions, false)
+            + &indexes
+            + "\n"
+            + &address_view(table, &self.view(), &roles)
+    }
+
+    /// The values of the columns of a decoded log.
+    ///
+    /// # Arguments
+    ///
+    /// - `log`: The log, decoded with the ABI of the event.
+    pub fn values(&self, log: DecodedLog) -> Result<Vec<Value>> {
+        self.columns
+            .iter()
+            .zip(log.params)
+            .map(|(column, param)| value(param.value, column.kind))
+            .collect()
+    }
+}
+
+/// Turns a name into a lowercase snake case SQL identifier, e.g. `sqrt_price_x96` for
+/// `sqrtPriceX96`, or `None` if nothing is left of it.
+fn identifier(name: &str) -> Option<String> {
+    let mut identifier = String::new();
+    let mut previous = None;
+
+    for c in name.trim_start_matches('_').chars() {
+        if !c.is_ascii_alphanumeric() && c != '_' {
+            continue;
+        }
+        if c.is_ascii_uppercase()
+            && previous.is_some_and(|p: char| p.is_ascii_lowercase() || p.is_ascii_digit())
+        {
+            identifier.push('_');
+        }
+        identifier.push(c.to_ascii_lowercase());
+        previous = Some(c);
+    }
+
+    match identifier.chars().next() {
+        Some(c) if !c.is_ascii_digit() => Some(identifier),
+        _ => None,
+    }
+}
+
+/// Derives the columns of the inputs of an event.
+///
+/// Unnamed inputs become `arg<position>`, inputs named after a common column are prefixed with
+/// `arg_`, and repeated names get the position of the input as a suffix.
+fn columns(inputs: &[EventParam]) -> Vec<DynamicColumn> {
+    let mut names = HashSet::new();
+
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| {
+            let mut name = match identifier(&input.name) {
+                Some(name) if RESERVED_COLUMNS.contains(&name.as_str()) => format!("arg_{name}"),
+                Some(name) => name,
+                None => format!("arg{i}"),
+            };
+            if !names.insert(name.clone()) {
+                name += &format!("_{i}");
+                names.insert(name.clone());
+            }
+
+            DynamicColumn {
+                name,
+                kind: column_kind(input),
+            }
+        })
+        .collect()
+}
+
+/// Maps the Solidity type of an event input to its column kind.
+pub fn column_kind(input: &EventParam) -> ColumnKind {
+    ColumnKind::of(&input.kind, input.indexed)
+}
+
+/// Converts a decoded value to the value of its column.
+fn value(token: Token, kind: ColumnKind) -> Result<Value> {
+    Ok(match (token, kind) {
+        (Token::Address(address), ColumnKind::Address) => Value::Address(address),
+        (Token::Int(raw), ColumnKind::Int) => Value::sql(I256::from_raw(raw).low_i32()),
+        (Token::Int(raw), ColumnKind::BigInt) => Value::sql(I256::from_raw(raw).low_i64()),
+        (Token::Int(raw), ColumnKind::Int256) => Value::sql::<WI256>(I256::from_raw(raw).into()),
+        (Token::Uint(value), ColumnKind::Int) => Value::sql(value.low_u32() as i32),
+        (Token::Uint(value), ColumnKind::BigInt) => Value::sql(value.low_u64() as i64),
+        (Token::Uint(value), ColumnKind::Uint128) => Value::sql::<Wu128>(value.low_u128().into()),
+        (Token::Uint(value), ColumnKind::Uint256) => Value::sql::<WU256>(value.into()),
+        (Token::Bool(value), ColumnKind::Bool) => Value::sql(value),
+        (Token::String(value), ColumnKind::Text) => Value::sql(value),
+        (Token::Bytes(value) | Token::FixedBytes(value), ColumnKind::Bytes) => Value::sql(value),
+        (token @ (Token::Array(_) | Token::FixedArray(_) | Token::Tuple(_)), ColumnKind::Text) => {
+            Value::sql(json(&token).to_string())
+        }
+        (token, kind) => return Err(eyre!("cannot store {token:?} as {kind:?}")),
+    })
+}
+
+/// Writes a decoded value as JSON, with checksummed addresses, decimal integers and hex bytes.
+fn json(token: &Token) -> serde_json::Value {
+    match token {
+        Token::Address(address) => to_checksum(address, None).into(),
+        Token::Int(raw) => I256::from_raw(*raw).to_string().into(),
+        Token::Uint(value) => value.to_string().into(),
+        Token::Bool(value) => (*value).into(),
+        Token::String(value) => value.clone().into(),
+        Token::Bytes(value) | Token::FixedBytes(value) => {
+            format!("0x{}", hex::encode(value)).into()
+        }
+        Token::Array(tokens) | Token::FixedArray(tokens) | Token::Tuple(tokens) => {
+            tokens.iter().map(json).collect()
+        }
+    }
+}
+
+/// Decodes a log with the ABI of the contract that emitted it.
+///
+/// # Arguments
+///
+/// - `contracts`: The indexed contracts.
+/// - `address`: The address of the contract that emitted the log.
+/// - `log`: The topics and data of the log.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the event of the log and its decoded inputs, or an error if no
+/// event of the contract matches the log.
+pub fn decode_dynamic<'a>(
+    contracts: &'a [DynamicContract],
+    address: H160,
+    log: &RawLog,
+) -> Result<(&'a DynamicEvent, DecodedLog), IndexerError> {
+    let unknown = |reason: String| IndexerError::Decode(reason.into());
+
+    let contract = contracts
+        .iter()
+        .find(|c| c.addresses.contains(&address))
+        .ok_or_else(|| unknown(format!("no contract is indexed at {address:?}")))?;
+    let selector = log
+        .topics
+        .first()
+        .ok_or_else(|| unknown("log has no topics".to_string()))?;
+    let event = contract.event(*selector).ok_or_else(|| {
+        unknown(format!(
+            "no event of {} matches {selector:?}",
+            contract.name
+        ))
+    })?;
+
+    Ok((event, event.event.parse_log(log.clone())?))
+}
+
+/// Returns the migration creating the tables, indexes and views of a contract, which `generate`
+/// writes next to its bindings.
+///
+/// The pool is stored in the tables of its `IndexedEvent` types, so its migration is that of
+/// their schemas. Any other contract is indexed by ABI.
+///
+/// # Arguments
+///
+/// - `contract`: The ABI of the contract.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the migration, or an error if the ABI is invalid.
+pub fn migration(contract: &ContractAbi) -> Result<String> {
+    let mut sql = format!(
+        "-- The tables of {} indexed by {}, generated by `generate`.\n\
+         -- They require the `ethereum_logs` and `addresses` tables of the indexer.\n",
+        contract.name,
+        match contract.name == POOL_CONTRACT {
+            true => "its event types",
+            false => "ABI",
+        }
+    );
+
+    if contract.name == POOL_CONTRACT {
+        for schema in SCHEMAS {
+            let roles: Vec<&str> = schema.address_columns().collect();
+            push_statements(&mut sql, &schema.create_table(false));
+            push_statements(&mut sql, &schema.create_indexes());
+            push_statements(
+                &mut sql,
+                &address_view(schema.table, &schema.view(), &roles),
+            );
+        }
+        return Ok(sql);
+    }
+
+    let abi: Abi = serde_json::from_str(&contract.abi)?;
+    let dynamic = DynamicContract::from_abi(&contract.name, &abi, Vec::new())?;
+    for event in &dynamic.events {
+        push_statements(&mut sql, &event.create_table());
+    }
+
+    Ok(sql)
+}
+
+/// Appends statements to a migration, without the indentation of the indexer source and
+/// without repeated blank lines.
+fn push_statements(sql: &mut String, statements: &str) {
+    for line in statements.lines() {
+        let line = line.strip_prefix("            ").unwrap_or(line).trim_end();
+        if !(line.is_empty() && sql.ends_with("\n\n")) {
+            *sql += line;
+            *sql += "\n";
+        }
+    }
+}
+
+impl DB {
+    /// Creates the tables and views of the events of dynamic contracts, if they do not already
+    /// exist.
+    ///
+    /// # Arguments
+    ///
+    /// - `contracts`: The indexed contracts.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the table creation.
+    pub async fn create_dynamic_tables(&self, contracts: &[DynamicContract]) -> Result<()> {
+        let sql: String = contracts
+            .iter()
+            .flat_map(|c| &c.events)
+            .map(DynamicEvent::create_table)
+            .collect();
+        self.client.batch_execute(&sql).await?;
+
+        Ok(())
+    }
+
+    /// Writes a decoded event of a dynamic contract and its log metadata into the open block.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: LogMeta of the log the event was decoded from.
+    /// - `event`: The event of the log.
+    /// - `log`: The decoded inputs of the log.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the id of the inserted row.
+    pub async fn insert_dynamic_event(
+        &self,
+        meta: &LogMeta,
+        event: &DynamicEvent,
+        log: DecodedLog,
+    ) -> Result<i32> {
+        let names: Vec<String> = event.columns.iter().map(|c| c.stored_name()).collect();
+        let values = event.values(log)?;
+
+        self.savepoint(async {
+            let id = Some(self.insert_transaction_logs(meta).await?);
+            self.insert_values(
+                &event.table,
+                &names,
+                id,
+                Some(meta.block_number.as_u64()),
+                values,
+            )
+            .await
+        })
+        .await
+    }
+}
+
+/// Indexes the contracts configured by ABI, instead of the pool.
+///
+/// Only the PostgreSQL backend is supported, without partitioning, raw logs, the Parquet sink
+/// or backfilling, which `Settings::validate` checks.
+///
+/// # Arguments
+///
+/// - `settings`: The settings of the indexer.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the indexing process.
+pub async fn initialise(settings: Settings) -> Result<()> {
+    let contracts = settings
+        .contracts
+        .iter()
+        .map(DynamicContract::load)
+        .collect::<Result<Vec<_>>>()?;
+
+    let db = DB::new().await?;
+    db.create_table().await?;
+    db.create_dynamic_tables(&contracts).await?;
+
+    if let Some(retention) = settings.retention.clone() {
+        retention::spawn(retention)?;
+    }
+
+    // Follow the logs of every address at once
+    let source = Source::new(
+        &settings,
+        contracts.iter().zip(&settings.contracts).map(|(c, s)| {
+            let selectors = c.events.iter().map(|e| e.event.signature()).collect();
+            (c.addresses[0], s.start_block, selectors)
+        }),
+    );
+    info!("Indexing {} contracts by ABI", contracts.len());
+
+    let provider = source.connect().await?;
+    let follow = source.follow(&provider, db.checkpoint().await?).await?;
+
+    // Each batch covers whole blocks, and is committed before the next one
+    let mut batches = follow.batches;
+    while let Some(batch) = batches.next().await {
+        let batch = batch?;
+        let logs = stream::iter(batch.logs.into_iter().map(Ok::<_, eyre::Report>));
+        index_dynamic(logs, &db, &contracts, &provider).await?;
+
+        // The blocks of the range without logs were processed too
+        db.cover_blocks(batch.from, batch.to).await?;
+    }
+
+    match follow.live {
+        Some(live) => index_dynamic(live, &db, &contracts, &provider).await,
+        None => Ok(()),
+    }
+}
+
+/// Decodes a stream of logs of dynamic contracts and writes them into the database.
+///
+/// Blocks are committed and rolled back like `decode::index_logs`. A log that fails to decode
+/// or to be written is kept as a dead letter.
+///
+/// # Arguments
+///
+/// - `stream`: A stream of logs of the contracts.
+/// - `db`: The database the events are written to.
+/// - `contracts`: The indexed contracts.
+/// - `times`: The timestamps of the blocks, usually the node.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the indexing process.
+pub async fn index_dynamic<St, E>(
+    mut stream: St,
+    db: &DB,
+    contracts: &[DynamicContract],
+    times: &dyn BlockTimes,
+) -> Result<()>
+where
+    St: Stream<Item = Result<Log, E>> + Unpin,
+    E: Into<eyre::Report>,
+{
+    let mut blocks = Blocks::resume(db, times).await?;
+
+    while let Some(log) = stream.next().await {
+        let log = log.map_err(Into::into)?;
+
+        // Pending logs have no position yet
+        let Some(meta) = log_meta(&log) else {
+            warn!("Skipping pending log {:?}", log.transaction_hash);
+            continue;
+        };
+        if !blocks.open(db, &meta).await? {
+            continue;
+        }
+
+        let raw = RawLog::from(log);
+        let written = match decode_dynamic(contracts, meta.address, &raw) {
+            Ok((event, decoded)) => match db.insert_dynamic_event(&meta, event, decoded).await {
+                Err(e) if is_transient(&e) => return Err(e),
+                result => result.map(|_| ()).map_err(|e| e.to_string()),
+            },
+            Err(e) => Err(format!("failed to decode log: {e}")),
+        };
+
+        if let Err(e) = written {
+            dead_letter(db, &meta, Some(&raw), &e).await?;
+        }
+    }
+
+    blocks.close(db).await
+}
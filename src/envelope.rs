@@ -0,0 +1,315 @@
+use ethers::{
+    prelude::LogMeta,
+    types::{H256, U256, U64},
+};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::export::{EventKind, LogRecord};
+use crate::types::encoding::{BigInt, Encoding};
+use crate::types::pool_types::usdc_weth_pool::*;
+use crate::types::{Address, Wu128, WI256, WU256};
+
+/// A decoded event together with its log metadata, serialized as a single JSON object.
+///
+/// Big integers are written with `encoding`, and read back from either encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Envelope {
+    /// The log metadata of the event.
+    pub meta: LogMeta,
+
+    /// The decoded event, one of the indexed event types.
+    pub event: USDC_WETH_POOLEvents,
+
+    /// The encoding of big integers.
+    pub encoding: Encoding,
+}
+
+impl Envelope {
+    /// Wraps an event with the default encoding.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: The log metadata of the event.
+    /// - `event`: The decoded event.
+    ///
+    /// # Returns
+    ///
+    /// Returns the envelope, or `None` for events that are not indexed.
+    pub fn new(meta: LogMeta, event: USDC_WETH_POOLEvents) -> Option<Self> {
+        match event {
+            USDC_WETH_POOLEvents::SwapFilter(_)
+            | USDC_WETH_POOLEvents::MintFilter(_)
+            | USDC_WETH_POOLEvents::BurnFilter(_)
+            | USDC_WETH_POOLEvents::FlashFilter(_) => Some(Self {
+                meta,
+                event,
+                encoding: Encoding::default(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Selects the encoding of big integers.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// The type of the wrapped event.
+    pub fn kind(&self) -> EventKind {
+        match self.event {
+            USDC_WETH_POOLEvents::SwapFilter(_) => EventKind::Swap,
+            USDC_WETH_POOLEvents::MintFilter(_) => EventKind::Mint,
+            USDC_WETH_POOLEvents::BurnFilter(_) => EventKind::Burn,
+            // `new` only wraps indexed events
+            _ => EventKind::Flash,
+        }
+    }
+}
+
+/// A stored event together with the log metadata the database holds for it, serialized in the
+/// layout of an [`Envelope`].
+///
+/// Metadata that was not stored, e.g. the hash of a block that is no longer kept, is written as
+/// null.
+#[derive(Debug, Clone, Copy)]
+pub struct StoredEnvelope<'a> {
+    /// The log metadata of the event.
+    pub log: &'a LogRecord,
+
+    /// The decoded event, one of the indexed event types.
+    pub event: &'a USDC_WETH_POOLEvents,
+
+    /// The encoding of big integers.
+    pub encoding: Encoding,
+}
+
+impl Serialize for StoredEnvelope<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let event = Payload::encode(self.event, self.encoding)
+            .ok_or_else(|| ser::Error::custom("the event is not indexed"))?;
+
+        Repr {
+            pool: self.log.address.map(Into::into),
+            block_number: self.log.block_number,
+            block_hash: self.log.block_hash,
+            transaction_hash: self.log.transaction_hash,
+            transaction_index: self.log.transaction_index,
+            log_index: self.log.log_index,
+            event,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// The serialized layout of an envelope.
+#[derive(Serialize, Deserialize)]
+struct Repr {
+    pool: Option<Address>,
+    block_number: Option<u64>,
+    block_hash: Option<H256>,
+    transaction_hash: Option<H256>,
+    transaction_index: Option<u64>,
+    log_index: Option<u64>,
+    event: Payload,
+}
+
+/// The serialized layout of an event, tagged with its type and with encoded big integers.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Payload {
+    Swap {
+        sender: Address,
+        recipient: Address,
+        amount0: String,
+        amount1: String,
+        sqrt_price_x96: String,
+        liquidity: String,
+        tick: i32,
+    },
+    Mint {
+        sender: Address,
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount: String,
+        amount0: String,
+        amount1: String,
+    },
+    Burn {
+        owner: Address,
+        tick_lower: i32,
+        tick_upper: i32,
+        amount: String,
+        amount0: String,
+        amount1: String,
+    },
+    Flash {
+        sender: Address,
+        recipient: Address,
+        amount0: String,
+        amount1: String,
+        paid0: String,
+        paid1: String,
+    },
+}
+
+impl Payload {
+    /// Encodes an event, or returns `None` for events that are not indexed.
+    fn encode(event: &USDC_WETH_POOLEvents, encoding: Encoding) -> Option<Self> {
+        let u256 = |value: U256| encoding.encode::<WU256>(&value.into());
+        let u128 = |value: u128| encoding.encode::<Wu128>(&value.into());
+
+        Some(match event {
+            USDC_WETH_POOLEvents::SwapFilter(e) => Self::Swap {
+                sender: e.sender.into(),
+                recipient: e.recipient.into(),
+                amount0: encoding.encode::<WI256>(&e.amount_0.into()),
+                amount1: encoding.encode::<WI256>(&e.amount_1.into()),
+                sqrt_price_x96: u256(e.sqrt_price_x96),
+                liquidity: u128(e.liquidity),
+                tick: e.tick,
+            },
+            USDC_WETH_POOLEvents::MintFilter(e) => Self::Mint {
+                sender: e.sender.into(),
+                owner: e.owner.into(),
+                tick_lower: e.tick_lower,
+                tick_upper: e.tick_upper,
+                amount: u128(e.amount),
+                amount0: u256(e.amount_0),
+                amount1: u256(e.amount_1),
+            },
+            USDC_WETH_POOLEvents::BurnFilter(e) => Self::Burn {
+                owner: e.owner.into(),
+                tick_lower: e.tick_lower,
+                tick_upper: e.tick_upper,
+                amount: u128(e.amount),
+                amount0: u256(e.amount_0),
+                amount1: u256(e.amount_1),
+            },
+            USDC_WETH_POOLEvents::FlashFilter(e) => Self::Flash {
+                sender: e.sender.into(),
+                recipient: e.recipient.into(),
+                amount0: u256(e.amount_0),
+                amount1: u256(e.amount_1),
+                paid0: u256(e.paid_0),
+                paid1: u256(e.paid_1),
+            },
+            _ => return None,
+        })
+    }
+
+    /// Decodes the event, reading big integers from either encoding.
+    fn decode(self) -> Result<USDC_WETH_POOLEvents, String> {
+        fn parse<T: BigInt>(text: &str) -> Result<T, String> {
+            T::parse(text)
+        }
+        let u256 = |text: &str| parse::<WU256>(text).map(|v| *v);
+        let u128 = |text: &str| parse::<Wu128>(text).map(|v| *v);
+        let i256 = |text: &str| parse::<WI256>(text).map(|v| *v);
+
+        Ok(match self {
+            Self::Swap {
+                sender,
+                recipient,
+                amount0,
+                amount1,
+                sqrt_price_x96,
+                liquidity,
+                tick,
+            } => USDC_WETH_POOLEvents::SwapFilter(SwapFilter {
+                sender: *sender,
+                recipient: *recipient,
+                amount_0: i256(&amount0)?,
+                amount_1: i256(&amount1)?,
+                sqrt_price_x96: u256(&sqrt_price_x96)?,
+                liquidity: u128(&liquidity)?,
+                tick,
+            }),
+            Self::Mint {
+                sender,
+                owner,
+                tick_lower,
+                tick_upper,
+                amount,
+                amount0,
+                amount1,
+            } => USDC_WETH_POOLEvents::MintFilter(MintFilter {
+                sender: *sender,
+                owner: *owner,
+                tick_lower,
+                tick_upper,
+                amount: u128(&amount)?,
+                amount_0: u256(&amount0)?,
+                amount_1: u256(&amount1)?,
+            }),
+            Self::Burn {
+                owner,
+                tick_lower,
+                tick_upper,
+                amount,
+                amount0,
+                amount1,
+            } => USDC_WETH_POOLEvents::BurnFilter(BurnFilter {
+                owner: *owner,
+                tick_lower,
+                tick_upper,
+                amount: u128(&amount)?,
+                amount_0: u256(&amount0)?,
+                amount_1: u256(&amount1)?,
+            }),
+            Self::Flash {
+                sender,
+                recipient,
+                amount0,
+                amount1,
+                paid0,
+                paid1,
+            } => USDC_WETH_POOLEvents::FlashFilter(FlashFilter {
+                sender: *sender,
+                recipient: *recipient,
+                amount_0: u256(&amount0)?,
+                amount_1: u256(&amount1)?,
+                paid_0: u256(&paid0)?,
+                paid_1: u256(&paid1)?,
+            }),
+        })
+    }
+}
+
+impl Serialize for Envelope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StoredEnvelope {
+            log: &LogRecord::from_meta(&self.meta),
+            event: &self.event,
+            encoding: self.encoding,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Envelope {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        fn required<T, E: de::Error>(value: Option<T>, name: &'static str) -> Result<T, E> {
+            value.ok_or_else(|| E::missing_field(name))
+        }
+
+        let repr = Repr::deserialize(deserializer)?;
+
+        Ok(Self {
+            meta: LogMeta {
+                address: *required(repr.pool, "pool")?,
+                block_number: U64::from(required(repr.block_number, "block_number")?),
+                block_hash: required(repr.block_hash, "block_hash")?,
+                transaction_hash: required(repr.transaction_hash, "transaction_hash")?,
+                transaction_index: U64::from(required(
+                    repr.transaction_index,
+                    "transaction_index",
+                )?),
+                log_index: U256::from(required(repr.log_index, "log_index")?),
+            },
+            event: repr.event.decode().map_err(de::Error::custom)?,
+            encoding: Encoding::default(),
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use ethers::{
+    contract::ContractError,
+    providers::{Provider, ProviderError, RpcError, Ws},
+};
+use std::error::Error;
+use std::io;
+use thiserror::Error;
+use tokio_postgres::error::SqlState;
+
+/// The JSON-RPC error codes of a node that is rate limiting or overloaded.
+const TRANSIENT_RPC_CODES: [i64; 2] = [429, -32005];
+
+/// The errors of the indexer, classified as transient or fatal.
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    /// The node rejected a request or the connection to it failed.
+    #[error("rpc error: {0}")]
+    Rpc(#[from] ProviderError),
+
+    /// A log or a value could not be decoded.
+    #[error("decode error: {0}")]
+    Decode(#[source] Box<dyn Error + Send + Sync>),
+
+    /// A stored value does not have the type or length of its column.
+    #[error("invalid {name} value: expected {expected} bytes, got {actual}")]
+    InvalidValue {
+        name: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// The PostgreSQL database rejected a statement or the connection to it failed.
+    #[error("storage error: {0}")]
+    Postgres(#[from] tokio_postgres::Error),
+
+    /// The SQLite database rejected a statement.
+    #[error("storage error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
+    /// The configuration is missing or invalid.
+    #[error("config error: {0}")]
+    Config(#[from] config::ConfigError),
+}
+
+impl IndexerError {
+    /// Whether retrying the failed operation later may succeed.
+    ///
+    /// Dropped connections, rate limits, serialization failures and deadlocks are transient.
+    /// Decoding, invalid values and configuration errors are fatal.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Rpc(e) => rpc_is_transient(e),
+            Self::Postgres(e) => postgres_is_transient(e),
+            Self::Sqlite(e) => sqlite_is_transient(e),
+            Self::Decode(_) | Self::InvalidValue { .. } | Self::Config(_) => false,
+        }
+    }
+}
+
+impl From<ethers::abi::Error> for IndexerError {
+    fn from(e: ethers::abi::Error) -> Self {
+        Self::Decode(Box::new(e))
+    }
+}
+
+impl From<ContractError<Provider<Ws>>> for IndexerError {
+    fn from(e: ContractError<Provider<Ws>>) -> Self {
+        match e {
+            ContractError::MiddlewareError { e } | ContractError::ProviderError { e } => {
+                Self::Rpc(e)
+            }
+            ContractError::DecodingError(e) => Self::Decode(Box::new(e)),
+            ContractError::AbiError(e) => Self::Decode(Box::new(e)),
+            ContractError::DetokenizationError(e) => Self::Decode(Box::new(e)),
+            e => Self::Rpc(ProviderError::CustomError(e.to_string())),
+        }
+    }
+}
+
+/// Whether an error report was caused by a transient error.
+///
+/// Reports hold either an `IndexerError` or the error of a dependency converted with `?`, so
+/// every error of the chain is classified.
+///
+/// # Arguments
+///
+/// - `report`: The error report.
+pub fn is_transient(report: &eyre::Report) -> bool {
+    report.chain().any(|e| {
+        if let Some(e) = e.downcast_ref::<IndexerError>() {
+            e.is_transient()
+        } else if let Some(e) = e.downcast_ref::<ProviderError>() {
+            rpc_is_transient(e)
+        } else if let Some(e) = e.downcast_ref::<tokio_postgres::Error>() {
+            postgres_is_transient(e)
+        } else if let Some(e) = e.downcast_ref::<rusqlite::Error>() {
+            sqlite_is_transient(e)
+        } else {
+            e.is::<io::Error>()
+        }
+    })
+}
+
+fn rpc_is_transient(e: &ProviderError) -> bool {
+    match e {
+        // Transport failures have no JSON-RPC response
+        ProviderError::JsonRpcClientError(_) => e
+            .as_error_response()
+            .is_none_or(|response| TRANSIENT_RPC_CODES.contains(&response.code)),
+        ProviderError::HTTPError(_) => true,
+        _ => false,
+    }
+}
+
+fn postgres_is_transient(e: &tokio_postgres::Error) -> bool {
+    if e.is_closed() || e.source().is_some_and(|source| source.is::<io::Error>()) {
+        return true;
+    }
+
+    e.code().is_some_and(|code| {
+        // Class 08 holds the connection exceptions
+        code.code().starts_with("08")
+            || [
+                SqlState::T_R_SERIALIZATION_FAILURE,
+                SqlState::T_R_DEADLOCK_DETECTED,
+                SqlState::ADMIN_SHUTDOWN,
+                SqlState::CANNOT_CONNECT_NOW,
+                SqlState::TOO_MANY_CONNECTIONS,
+            ]
+            .contains(code)
+    })
+}
+
+fn sqlite_is_transient(e: &rusqlite::Error) -> bool {
+    matches!(
+        e.sqlite_error_code(),
+        Some(rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked)
+    )
+}
@@ -0,0 +1,81 @@
+use eyre::Result;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::export::{Cell, ExportFilter, Table, TableWriter, Tables};
+
+/// Writes one table to a CSV file, e.g. `swaps.csv`, a page of records at a time.
+///
+/// The file starts with a header row. Values never contain separators or quotes, since
+/// addresses are written as hex and amounts as decimal strings.
+pub struct CsvWriter {
+    table: Table,
+    out: BufWriter<File>,
+}
+
+impl CsvWriter {
+    /// Creates the file of `table` in `dir` and writes its header row.
+    ///
+    /// # Arguments
+    ///
+    /// - `dir`: The directory the file is written to.
+    /// - `table`: The table written to the file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the writer, or an error if the file cannot be created.
+    pub fn create(dir: &Path, table: Table) -> Result<Self> {
+        let mut out = BufWriter::new(File::create(dir.join(format!("{}.csv", table.name())))?);
+
+        let header = Tables::default().text_table(table).header;
+        writeln!(out, "{}", header.join(","))?;
+
+        Ok(Self { table, out })
+    }
+}
+
+impl TableWriter for CsvWriter {
+    fn write(&mut self, page: &Tables) -> Result<()> {
+        for row in &page.text_table(self.table).rows {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|cell| match cell {
+                    Cell::Int(n) => n.to_string(),
+                    Cell::Text(s) => s.clone(),
+                    Cell::Null => String::new(),
+                })
+                .collect();
+            writeln!(self.out, "{}", cells.join(","))?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        self.out.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Writes the tables selected by `filter` to CSV files in `dir`, e.g. `swaps.csv`.
+///
+/// # Arguments
+///
+/// - `tables`: The records to write.
+/// - `filter`: The filter the records were read with.
+/// - `dir`: The directory the files are written to.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the write.
+pub fn write_tables(tables: &Tables, filter: &ExportFilter, dir: &Path) -> Result<()> {
+    for table in Table::selected(filter) {
+        let mut writer = Box::new(CsvWriter::create(dir, table)?);
+        writer.write(tables)?;
+        writer.finish()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,772 @@
+use clap::{ArgEnum, Args};
+use ethers::{
+    prelude::LogMeta,
+    types::{H160, H256},
+};
+use eyre::Result;
+use log::info;
+use postgres_types::ToSql;
+use std::fs;
+use std::path::Path;
+use tokio_postgres::Row;
+
+pub mod csv;
+pub mod ndjson;
+pub mod parquet;
+
+use crate::database::DB;
+use crate::error::IndexerError;
+use crate::indexed::IndexedEvent;
+use crate::types::pool_types::usdc_weth_pool::*;
+use crate::types::Address;
+
+/// The file format written by the `Export` command.
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// One typed Parquet file per table.
+    Parquet,
+
+    /// One CSV file per table, with a header row.
+    Csv,
+
+    /// One newline-delimited JSON file per table.
+    Ndjson,
+}
+
+/// The event tables that can be exported.
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventKind {
+    Swap,
+    Mint,
+    Burn,
+    Flash,
+}
+
+impl EventKind {
+    /// Every indexed event type.
+    pub const ALL: [EventKind; 4] = [Self::Swap, Self::Mint, Self::Burn, Self::Flash];
+
+    /// The lowercase name of the event type.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Swap => "swap",
+            Self::Mint => "mint",
+            Self::Burn => "burn",
+            Self::Flash => "flash",
+        }
+    }
+
+    /// The database table holding the events.
+    pub fn table(&self) -> &'static str {
+        match self {
+            Self::Swap => "swap_logs",
+            Self::Mint => "mint_logs",
+            Self::Burn => "burn_logs",
+            Self::Flash => "flash_logs",
+        }
+    }
+
+    /// The view presenting the events with their addresses.
+    pub fn view(&self) -> &'static str {
+        match self {
+            Self::Swap => "swap_events",
+            Self::Mint => "mint_events",
+            Self::Burn => "burn_events",
+            Self::Flash => "flash_events",
+        }
+    }
+}
+
+/// The number of rows `export` reads and writes at a time.
+pub const PAGE_SIZE: i64 = 10_000;
+
+/// A table written by an export.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Table {
+    /// The `ethereum_logs` table.
+    Logs,
+
+    /// The table of an event type.
+    Events(EventKind),
+}
+
+impl Table {
+    /// The name of the table, used as the file name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Logs => "logs",
+            Self::Events(EventKind::Swap) => "swaps",
+            Self::Events(EventKind::Mint) => "mints",
+            Self::Events(EventKind::Burn) => "burns",
+            Self::Events(EventKind::Flash) => "flashes",
+        }
+    }
+
+    /// Returns the tables selected by `filter`, in the order they are exported.
+    ///
+    /// The `ethereum_logs` table is only selected when the filter does not select a single
+    /// event type.
+    pub fn selected(filter: &ExportFilter) -> Vec<Table> {
+        let logs = filter.event.is_none().then_some(Self::Logs);
+        let events = EventKind::ALL
+            .into_iter()
+            .filter(|kind| filter.includes(*kind))
+            .map(Self::Events);
+
+        logs.into_iter().chain(events).collect()
+    }
+}
+
+/// Filters applied to the exported rows.
+///
+/// Every filter is optional. A filter on a column a table does not have, such as `owner` on
+/// swaps, leaves that table empty.
+#[derive(Args, Clone, Debug, Default)]
+pub struct ExportFilter {
+    /// The first block to export.
+    #[clap(long)]
+    pub from_block: Option<u64>,
+
+    /// The last block to export.
+    #[clap(long)]
+    pub to_block: Option<u64>,
+
+    /// The earliest block time to export, as an RFC 3339 timestamp.
+    #[clap(long)]
+    pub from_time: Option<String>,
+
+    /// The latest block time to export, as an RFC 3339 timestamp.
+    #[clap(long)]
+    pub to_time: Option<String>,
+
+    /// Only export this event type.
+    #[clap(long, arg_enum)]
+    pub event: Option<EventKind>,
+
+    /// Only export events with this sender address.
+    #[clap(long)]
+    pub sender: Option<H160>,
+
+    /// Only export events with this owner address.
+    #[clap(long)]
+    pub owner: Option<H160>,
+}
+
+impl ExportFilter {
+    /// Returns `true` if rows of `kind` can be exported.
+    pub(crate) fn includes(&self, kind: EventKind) -> bool {
+        self.event.is_none_or(|event| event == kind)
+    }
+
+    /// Builds the WHERE clause and parameters for an event table `e` joined with
+    /// `ethereum_logs` as `l`. Time filters apply to the timestamp of the block, as the range of
+    /// blocks between them, so blocks stored without a timestamp never match.
+    ///
+    /// Returns `None` if the filter needs a column the table does not have.
+    fn where_clause(&self, columns: &[&str]) -> Option<(String, Vec<Box<dyn ToSql + Sync>>)> {
+        let mut conditions = Vec::new();
+        let mut params: Vec<Box<dyn ToSql + Sync>> = Vec::new();
+
+        let mut push = |condition: &str, param: Box<dyn ToSql + Sync>| {
+            params.push(param);
+            conditions.push(condition.replace('?', &format!("${}", params.len())));
+        };
+
+        if let Some(block) = self.from_block {
+            push(
+                "l.block_number >= ?",
+                Box::new(block.to_be_bytes().to_vec()),
+            );
+        }
+        if let Some(block) = self.to_block {
+            push(
+                "l.block_number <= ?",
+                Box::new(block.to_be_bytes().to_vec()),
+            );
+        }
+        // Block times only increase with the block number
+        if let Some(time) = &self.from_time {
+            push(
+                "l.block_number >= (
+                    SELECT int8send(MIN(number)) FROM blocks
+                    WHERE timestamp >= ?::text::timestamptz
+                )",
+                Box::new(time.clone()),
+            );
+        }
+        if let Some(time) = &self.to_time {
+            push(
+                "l.block_number <= (
+                    SELECT int8send(MAX(number)) FROM blocks
+                    WHERE timestamp <= ?::text::timestamptz
+                )",
+                Box::new(time.clone()),
+            );
+        }
+        for (column, address) in [
+            ("sender_address", self.sender),
+            ("owner_address", self.owner),
+        ] {
+            if let Some(address) = address {
+                if !columns.contains(&column) {
+                    return None;
+                }
+                push(
+                    &format!("e.{column} = ?"),
+                    Box::new(address.as_bytes().to_vec()),
+                );
+            }
+        }
+
+        let clause = match conditions.is_empty() {
+            true => String::new(),
+            false => format!("WHERE {}", conditions.join(" AND ")),
+        };
+
+        Some((clause, params))
+    }
+}
+
+/// Log metadata of an exported event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogRecord {
+    /// The id of the `ethereum_logs` row, if the event was read from the database.
+    pub ethereum_log_id: Option<i32>,
+
+    /// The number of the block containing the log.
+    pub block_number: Option<u64>,
+
+    /// The hash of the transaction that emitted the log.
+    pub transaction_hash: Option<H256>,
+
+    /// The address of the contract that emitted the log.
+    pub address: Option<H160>,
+
+    /// The position of the log in its block, unknown for rows indexed before it was stored.
+    pub log_index: Option<u64>,
+
+    /// The hash of the block containing the log, unknown if the block is not stored.
+    pub block_hash: Option<H256>,
+
+    /// The position of the transaction in its block, unknown for rows indexed before it was
+    /// stored.
+    pub transaction_index: Option<u64>,
+}
+
+impl LogRecord {
+    /// Builds a `LogRecord` from the log metadata of a decoded event.
+    pub fn from_meta(meta: &LogMeta) -> Self {
+        Self {
+            ethereum_log_id: None,
+            block_number: Some(meta.block_number.as_u64()),
+            transaction_hash: Some(meta.transaction_hash),
+            address: Some(meta.address),
+            log_index: Some(meta.log_index.as_u64()),
+            block_hash: Some(meta.block_hash),
+            transaction_index: Some(meta.transaction_index.as_u64()),
+        }
+    }
+
+    /// Reads the log columns of a row joined with `ethereum_logs`.
+    pub(crate) fn from_row(row: &Row) -> Result<Self, IndexerError> {
+        let block_number = row
+            .try_get::<_, Option<Vec<u8>>>("block_number")?
+            .map(|raw| fixed::<8>("block_number", raw))
+            .transpose()?
+            .map(u64::from_be_bytes);
+        let transaction_hash = row
+            .try_get::<_, Option<Vec<u8>>>("transaction_hash")?
+            .map(|raw| fixed::<32>("transaction_hash", raw))
+            .transpose()?
+            .map(H256);
+        let block_hash = row
+            .try_get::<_, Option<Vec<u8>>>("block_hash")?
+            .map(|raw| fixed::<32>("block_hash", raw))
+            .transpose()?
+            .map(H256);
+
+        Ok(Self {
+            ethereum_log_id: row.try_get("ethereum_log_id")?,
+            block_number,
+            transaction_hash,
+            address: row.try_get::<_, Option<Address>>("address")?.map(|a| *a),
+            log_index: row
+                .try_get::<_, Option<i64>>("log_index")?
+                .map(|i| i as u64),
+            block_hash,
+            transaction_index: row
+                .try_get::<_, Option<i64>>("transaction_index")?
+                .map(|i| i as u64),
+        })
+    }
+}
+
+/// Checks the length of a stored byte string.
+pub(crate) fn fixed<const N: usize>(
+    name: &'static str,
+    raw: Vec<u8>,
+) -> Result<[u8; N], IndexerError> {
+    let actual = raw.len();
+    raw.try_into().map_err(|_| IndexerError::InvalidValue {
+        name,
+        expected: N,
+        actual,
+    })
+}
+
+/// A decoded event together with its log metadata.
+pub type Record<T> = (LogRecord, T);
+
+/// A human-readable value of an exported column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    /// A small integer, such as a block number or tick.
+    Int(i64),
+
+    /// A hex string for addresses and hashes, or a decimal string for big integers.
+    Text(String),
+
+    /// A missing value.
+    Null,
+}
+
+/// Renders a row as named, human-readable columns for the text export formats.
+pub trait Columns {
+    /// Returns the column names and values of the row.
+    fn columns(&self) -> Vec<(&'static str, Cell)>;
+}
+
+/// A table rendered for the text export formats.
+#[derive(Debug, Clone)]
+pub struct TextTable {
+    /// The name of the table, used as the file name.
+    pub name: &'static str,
+
+    /// The column names.
+    pub header: Vec<&'static str>,
+
+    /// The rows, in the order of the header.
+    pub rows: Vec<Vec<Cell>>,
+}
+
+impl TextTable {
+    fn new<T: Columns + Default>(name: &'static str, rows: &[T]) -> Self {
+        Self {
+            name,
+            header: T::default().columns().into_iter().map(|(n, _)| n).collect(),
+            rows: rows
+                .iter()
+                .map(|row| row.columns().into_iter().map(|(_, c)| c).collect())
+                .collect(),
+        }
+    }
+}
+
+fn hex<T: std::fmt::Debug>(value: T) -> Cell {
+    Cell::Text(format!("{value:?}"))
+}
+
+fn decimal<T: ToString>(value: T) -> Cell {
+    Cell::Text(value.to_string())
+}
+
+impl Columns for LogRecord {
+    fn columns(&self) -> Vec<(&'static str, Cell)> {
+        vec![
+            (
+                "ethereum_log_id",
+                self.ethereum_log_id
+                    .map_or(Cell::Null, |id| Cell::Int(id.into())),
+            ),
+            (
+                "block_number",
+                self.block_number
+                    .map_or(Cell::Null, |n| Cell::Int(n as i64)),
+            ),
+            (
+                "transaction_hash",
+                self.transaction_hash.map_or(Cell::Null, hex),
+            ),
+            ("address", self.address.map_or(Cell::Null, hex)),
+        ]
+    }
+}
+
+impl<T: Columns> Columns for Record<T> {
+    fn columns(&self) -> Vec<(&'static str, Cell)> {
+        let mut columns = self.0.columns();
+        columns.extend(self.1.columns());
+        columns
+    }
+}
+
+impl Columns for SwapFilter {
+    fn columns(&self) -> Vec<(&'static str, Cell)> {
+        vec![
+            ("sender", hex(self.sender)),
+            ("recipient", hex(self.recipient)),
+            ("amount0", decimal(self.amount_0)),
+            ("amount1", decimal(self.amount_1)),
+            ("sqrt_price_x96", decimal(self.sqrt_price_x96)),
+            ("liquidity", decimal(self.liquidity)),
+            ("tick", Cell::Int(self.tick.into())),
+        ]
+    }
+}
+
+impl Columns for MintFilter {
+    fn columns(&self) -> Vec<(&'static str, Cell)> {
+        vec![
+            ("sender", hex(self.sender)),
+            ("owner", hex(self.owner)),
+            ("tick_lower", Cell::Int(self.tick_lower.into())),
+            ("tick_upper", Cell::Int(self.tick_upper.into())),
+            ("amount", decimal(self.amount)),
+            ("amount0", decimal(self.amount_0)),
+            ("amount1", decimal(self.amount_1)),
+        ]
+    }
+}
+
+impl Columns for BurnFilter {
+    fn columns(&self) -> Vec<(&'static str, Cell)> {
+        vec![
+            ("owner", hex(self.owner)),
+            ("tick_lower", Cell::Int(self.tick_lower.into())),
+            ("tick_upper", Cell::Int(self.tick_upper.into())),
+            ("amount", decimal(self.amount)),
+            ("amount0", decimal(self.amount_0)),
+            ("amount1", decimal(self.amount_1)),
+        ]
+    }
+}
+
+impl Columns for FlashFilter {
+    fn columns(&self) -> Vec<(&'static str, Cell)> {
+        vec![
+            ("sender", hex(self.sender)),
+            ("recipient", hex(self.recipient)),
+            ("amount0", decimal(self.amount_0)),
+            ("amount1", decimal(self.amount_1)),
+            ("paid0", decimal(self.paid_0)),
+            ("paid1", decimal(self.paid_1)),
+        ]
+    }
+}
+
+/// The contents of every event table, read back as typed records.
+#[derive(Debug, Clone, Default)]
+pub struct Tables {
+    /// Rows of `ethereum_logs`.
+    pub logs: Vec<LogRecord>,
+
+    /// Rows of `swap_logs`.
+    pub swaps: Vec<Record<SwapFilter>>,
+
+    /// Rows of `mint_logs`.
+    pub mints: Vec<Record<MintFilter>>,
+
+    /// Rows of `burn_logs`.
+    pub burns: Vec<Record<BurnFilter>>,
+
+    /// Rows of `flash_logs`.
+    pub flashes: Vec<Record<FlashFilter>>,
+}
+
+impl Tables {
+    /// Reads a page of `table` from the database: up to `PAGE_SIZE` rows matching `filter`,
+    /// after the row with id `after`, in the order of their ids.
+    ///
+    /// Each event is joined with its `ethereum_logs` row, so the records carry their block
+    /// number and transaction hash.
+    ///
+    /// # Arguments
+    ///
+    /// - `db`: A reference to the PostgreSQL database instance.
+    /// - `table`: The table to read.
+    /// - `filter`: The filters applied to the rows.
+    /// - `after`: The id of the last row of the previous page, or 0 for the first page.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the page and the id to read the next page after, which is
+    /// `None` once the table is exhausted, or an error if a query fails.
+    pub async fn read_page(
+        db: &DB,
+        table: Table,
+        filter: &ExportFilter,
+        after: i32,
+    ) -> Result<(Self, Option<i32>)> {
+        let mut page = Self::default();
+
+        let last = match table {
+            Table::Logs => {
+                let (logs, last) = Self::read_logs(db, filter, after).await?;
+                page.logs = logs;
+                last
+            }
+            Table::Events(kind @ EventKind::Swap) => {
+                let (rows, last) = Self::read_table(db, kind, filter, after).await?;
+                page.swaps = rows;
+                last
+            }
+            Table::Events(kind @ EventKind::Mint) => {
+                let (rows, last) = Self::read_table(db, kind, filter, after).await?;
+                page.mints = rows;
+                last
+            }
+            Table::Events(kind @ EventKind::Burn) => {
+                let (rows, last) = Self::read_table(db, kind, filter, after).await?;
+                page.burns = rows;
+                last
+            }
+            Table::Events(kind @ EventKind::Flash) => {
+                let (rows, last) = Self::read_table(db, kind, filter, after).await?;
+                page.flashes = rows;
+                last
+            }
+        };
+
+        Ok((page, last))
+    }
+
+    async fn read_logs(
+        db: &DB,
+        filter: &ExportFilter,
+        after: i32,
+    ) -> Result<(Vec<LogRecord>, Option<i32>)> {
+        // Address filters only apply to event tables.
+        let filter = ExportFilter {
+            sender: None,
+            owner: None,
+            ..filter.clone()
+        };
+        let (clause, params) = filter.where_clause(&[]).unwrap_or_default();
+        let (clause, params) = after_id(clause, params, "l.id", after);
+
+        let rows = db
+            .client
+            .query(
+                &format!(
+                    "
+                   SELECT l.id AS ethereum_log_id, l.transaction_hash, l.block_number, l.address, l.log_index,
+                          l.transaction_index, b.hash AS block_hash
+                   FROM ethereum_logs l
+                   LEFT JOIN blocks b ON int8send(b.number) = l.block_number
+                   {clause}
+                   ORDER BY l.id
+                   LIMIT {PAGE_SIZE}
+                   "
+                ),
+                &params.iter().map(|p| p.as_ref() as _).collect::<Vec<_>>(),
+            )
+            .await?;
+
+        let last = next_page(&rows, "ethereum_log_id")?;
+        let logs = rows
+            .iter()
+            .map(LogRecord::from_row)
+            .collect::<Result<_, _>>()?;
+
+        Ok((logs, last))
+    }
+
+    async fn read_table<T: IndexedEvent>(
+        db: &DB,
+        kind: EventKind,
+        filter: &ExportFilter,
+        after: i32,
+    ) -> Result<(Vec<Record<T>>, Option<i32>)> {
+        let columns = match kind {
+            EventKind::Swap | EventKind::Flash => ["sender_address"].as_slice(),
+            EventKind::Mint => ["sender_address", "owner_address"].as_slice(),
+            EventKind::Burn => ["owner_address"].as_slice(),
+        };
+        let Some((clause, params)) = filter.where_clause(columns) else {
+            return Ok((Vec::new(), None));
+        };
+        let (clause, params) = after_id(clause, params, "e.id", after);
+
+        let rows = db
+            .client
+            .query(
+                &format!(
+                    "
+                   SELECT e.*, l.transaction_hash, l.block_number, l.address, l.log_index,
+                          l.transaction_index, b.hash AS block_hash
+                   FROM {} e
+                   LEFT JOIN ethereum_logs l ON l.id = e.ethereum_log_id
+                   LEFT JOIN blocks b ON int8send(b.number) = l.block_number
+                   {clause}
+                   ORDER BY e.id
+                   LIMIT {PAGE_SIZE}
+                   ",
+                    kind.view()
+                ),
+                &params.iter().map(|p| p.as_ref() as _).collect::<Vec<_>>(),
+            )
+            .await?;
+
+        let last = next_page(&rows, "id")?;
+        let records = rows
+            .iter()
+            .map(|row| Ok((LogRecord::from_row(row)?, T::from_row(row)?)))
+            .collect::<Result<_, IndexerError>>()?;
+
+        Ok((records, last))
+    }
+
+    /// Appends a decoded event and its log metadata.
+    pub fn push(&mut self, meta: &LogMeta, event: &USDC_WETH_POOLEvents) {
+        let log = LogRecord::from_meta(meta);
+
+        match event {
+            USDC_WETH_POOLEvents::SwapFilter(f) => self.swaps.push((log.clone(), f.clone())),
+            USDC_WETH_POOLEvents::MintFilter(f) => self.mints.push((log.clone(), f.clone())),
+            USDC_WETH_POOLEvents::BurnFilter(f) => self.burns.push((log.clone(), f.clone())),
+            USDC_WETH_POOLEvents::FlashFilter(f) => self.flashes.push((log.clone(), f.clone())),
+            _ => return,
+        }
+
+        self.logs.push(log);
+    }
+
+    /// Returns the records of an event table with their events.
+    pub fn events(&self, kind: EventKind) -> Vec<(&LogRecord, USDC_WETH_POOLEvents)> {
+        fn wrap<T: Clone + Into<USDC_WETH_POOLEvents>>(
+            records: &[Record<T>],
+        ) -> Vec<(&LogRecord, USDC_WETH_POOLEvents)> {
+            records
+                .iter()
+                .map(|(log, event)| (log, event.clone().into()))
+                .collect()
+        }
+
+        match kind {
+            EventKind::Swap => wrap(&self.swaps),
+            EventKind::Mint => wrap(&self.mints),
+            EventKind::Burn => wrap(&self.burns),
+            EventKind::Flash => wrap(&self.flashes),
+        }
+    }
+
+    /// Renders the tables selected by `filter` for the text export formats.
+    pub fn text_tables(&self, filter: &ExportFilter) -> Vec<TextTable> {
+        Table::selected(filter)
+            .into_iter()
+            .map(|table| self.text_table(table))
+            .collect()
+    }
+
+    /// Renders the records of `table` for the text export formats.
+    pub fn text_table(&self, table: Table) -> TextTable {
+        let name = table.name();
+
+        match table {
+            Table::Logs => TextTable::new(name, &self.logs),
+            Table::Events(EventKind::Swap) => TextTable::new(name, &self.swaps),
+            Table::Events(EventKind::Mint) => TextTable::new(name, &self.mints),
+            Table::Events(EventKind::Burn) => TextTable::new(name, &self.burns),
+            Table::Events(EventKind::Flash) => TextTable::new(name, &self.flashes),
+        }
+    }
+
+    /// Moves every record of `other` into `self`.
+    pub fn append(&mut self, other: &mut Tables) {
+        self.logs.append(&mut other.logs);
+        self.swaps.append(&mut other.swaps);
+        self.mints.append(&mut other.mints);
+        self.burns.append(&mut other.burns);
+        self.flashes.append(&mut other.flashes);
+    }
+
+    /// Removes every record above `block_number`.
+    pub fn truncate_to_block(&mut self, block_number: u64) {
+        let keep = |log: &LogRecord| log.block_number.is_none_or(|n| n <= block_number);
+
+        self.logs.retain(keep);
+        self.swaps.retain(|(log, _)| keep(log));
+        self.mints.retain(|(log, _)| keep(log));
+        self.burns.retain(|(log, _)| keep(log));
+        self.flashes.retain(|(log, _)| keep(log));
+    }
+}
+
+/// Adds the condition `column > after` to a WHERE clause built by `ExportFilter::where_clause`.
+fn after_id(
+    clause: String,
+    mut params: Vec<Box<dyn ToSql + Sync>>,
+    column: &str,
+    after: i32,
+) -> (String, Vec<Box<dyn ToSql + Sync>>) {
+    params.push(Box::new(after));
+    let condition = format!("{column} > ${}", params.len());
+
+    let clause = match clause.is_empty() {
+        true => format!("WHERE {condition}"),
+        false => format!("{clause} AND {condition}"),
+    };
+
+    (clause, params)
+}
+
+/// Returns the id of the last row of a full page, or `None` if the page is the last one.
+fn next_page(rows: &[Row], id: &str) -> Result<Option<i32>> {
+    match rows.last() {
+        Some(row) if rows.len() as i64 == PAGE_SIZE => Ok(Some(row.try_get(id)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Writes one table of an export to its file, a page of records at a time.
+pub trait TableWriter {
+    /// Appends the records of the table held by `page`.
+    fn write(&mut self, page: &Tables) -> Result<()>;
+
+    /// Completes the file.
+    fn finish(self: Box<Self>) -> Result<()>;
+}
+
+/// Exports the event tables matching `filter` to files in `out`.
+///
+/// The tables are read and written a page of `PAGE_SIZE` rows at a time, so an export does not
+/// hold more than a page in memory however large the tables are.
+///
+/// # Arguments
+///
+/// - `db`: A reference to the PostgreSQL database instance.
+/// - `format`: The file format to write.
+/// - `filter`: The filters applied to the rows.
+/// - `out`: The directory the files are written to, created if missing.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the export.
+pub async fn export(db: &DB, format: Format, filter: &ExportFilter, out: &Path) -> Result<()> {
+    fs::create_dir_all(out)?;
+
+    for table in Table::selected(filter) {
+        let mut writer: Box<dyn TableWriter> = match format {
+            Format::Parquet => Box::new(parquet::ParquetWriter::create(out, table, "")?),
+            Format::Csv => Box::new(csv::CsvWriter::create(out, table)?),
+            Format::Ndjson => Box::new(ndjson::NdjsonWriter::create(out, table)?),
+        };
+
+        let mut after = 0;
+        loop {
+            let (page, last) = Tables::read_page(db, table, filter, after).await?;
+            writer.write(&page)?;
+
+            match last {
+                Some(last) => after = last,
+                None => break,
+            }
+        }
+
+        writer.finish()?;
+    }
+
+    info!("Exported tables to {}", out.display());
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use eyre::Result;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::envelope::StoredEnvelope;
+use crate::export::{Cell, ExportFilter, Table, TableWriter, Tables};
+use crate::types::encoding::Encoding;
+
+/// Writes one table to a newline-delimited JSON file, e.g. `swaps.ndjson`, a page of records
+/// at a time.
+///
+/// Events are written in the layout of an [`Envelope`](crate::envelope::Envelope), after the
+/// id of their log row. The logs table keeps its flat rows. Big integers are written as
+/// decimal strings, since JSON numbers lose precision above 2^53.
+pub struct NdjsonWriter {
+    table: Table,
+    out: BufWriter<File>,
+}
+
+impl NdjsonWriter {
+    /// Creates the file of `table` in `dir`.
+    ///
+    /// # Arguments
+    ///
+    /// - `dir`: The directory the file is written to.
+    /// - `table`: The table written to the file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the writer, or an error if the file cannot be created.
+    pub fn create(dir: &Path, table: Table) -> Result<Self> {
+        let out = BufWriter::new(File::create(dir.join(format!("{}.ndjson", table.name())))?);
+
+        Ok(Self { table, out })
+    }
+}
+
+impl TableWriter for NdjsonWriter {
+    fn write(&mut self, page: &Tables) -> Result<()> {
+        if let Table::Events(kind) = self.table {
+            for (log, event) in page.events(kind) {
+                let line = EventLine {
+                    ethereum_log_id: log.ethereum_log_id,
+                    envelope: StoredEnvelope {
+                        log,
+                        event: &event,
+                        encoding: Encoding::Decimal,
+                    },
+                };
+                serde_json::to_writer(&mut self.out, &line)?;
+                writeln!(self.out)?;
+            }
+
+            return Ok(());
+        }
+
+        let table = page.text_table(self.table);
+
+        for row in &table.rows {
+            let object: Map<String, Value> = table
+                .header
+                .iter()
+                .zip(row)
+                .map(|(name, cell)| {
+                    let value = match cell {
+                        Cell::Int(n) => Value::from(*n),
+                        Cell::Text(s) => Value::from(s.as_str()),
+                        Cell::Null => Value::Null,
+                    };
+                    (name.to_string(), value)
+                })
+                .collect();
+
+            serde_json::to_writer(&mut self.out, &object)?;
+            writeln!(self.out)?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        self.out.flush()?;
+
+        Ok(())
+    }
+}
+
+/// A line of an event table.
+#[derive(Serialize)]
+struct EventLine<'a> {
+    ethereum_log_id: Option<i32>,
+
+    #[serde(flatten)]
+    envelope: StoredEnvelope<'a>,
+}
+
+/// Writes the tables selected by `filter` to newline-delimited JSON files in `dir`, e.g.
+/// `swaps.ndjson`.
+///
+/// # Arguments
+///
+/// - `tables`: The records to write.
+/// - `filter`: The filter the records were read with.
+/// - `dir`: The directory the files are written to.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the write.
+pub fn write_tables(tables: &Tables, filter: &ExportFilter, dir: &Path) -> Result<()> {
+    for table in Table::selected(filter) {
+        let mut writer = Box::new(NdjsonWriter::create(dir, table)?);
+        writer.write(tables)?;
+        writer.finish()?;
+    }
+
+    Ok(())
+}
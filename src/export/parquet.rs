@@ -0,0 +1,280 @@
+use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, UInt64Array};
+use arrow_schema::{DataType, Field, Schema};
+use eyre::Result;
+use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::export::{EventKind, ExportFilter, LogRecord, Record, Table, TableWriter, Tables};
+use crate::types::pool_types::usdc_weth_pool::*;
+
+// Big integers are written as decimal strings, since a U256 does not fit the 76 digits of the
+// widest Parquet decimal. Addresses and hashes are written as 0x-prefixed hex strings.
+
+/// Writes one table to a Snappy compressed Parquet file, e.g. `swaps.parquet`, with one row
+/// group per page of records.
+pub struct ParquetWriter {
+    table: Table,
+    writer: ArrowWriter<File>,
+}
+
+impl ParquetWriter {
+    /// Creates the file of `table` in `dir`, named after the table followed by `suffix`, e.g.
+    /// `swaps{suffix}.parquet`.
+    ///
+    /// # Arguments
+    ///
+    /// - `dir`: The directory the file is written to.
+    /// - `table`: The table written to the file.
+    /// - `suffix`: A suffix appended to the file name.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the writer, or an error if the file cannot be created.
+    pub fn create(dir: &Path, table: Table, suffix: &str) -> Result<Self> {
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+
+        let path = dir.join(format!("{}{suffix}.parquet", table.name()));
+        let schema = batch(&Tables::default(), table)?.schema();
+        let writer = ArrowWriter::try_new(File::create(path)?, schema, Some(props))?;
+
+        Ok(Self { table, writer })
+    }
+}
+
+impl TableWriter for ParquetWriter {
+    fn write(&mut self, page: &Tables) -> Result<()> {
+        let batch = batch(page, self.table)?;
+
+        if batch.num_rows() > 0 {
+            self.writer.write(&batch)?;
+            // Close the row group, so the writer does not buffer more than a page
+            self.writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<()> {
+        self.writer.close()?;
+
+        Ok(())
+    }
+}
+
+/// Writes every table selected by `filter` to a Parquet file in `dir`.
+///
+/// The files are named after the table, followed by `suffix`, e.g. `swaps{suffix}.parquet`.
+///
+/// # Arguments
+///
+/// - `tables`: The records to write.
+/// - `filter`: The filters selecting the tables to write.
+/// - `dir`: The directory the files are written to.
+/// - `suffix`: A suffix appended to every file name.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the write.
+pub fn write_tables(
+    tables: &Tables,
+    filter: &ExportFilter,
+    dir: &Path,
+    suffix: &str,
+) -> Result<()> {
+    for table in Table::selected(filter) {
+        let mut writer = Box::new(ParquetWriter::create(dir, table, suffix)?);
+        writer.write(tables)?;
+        writer.finish()?;
+    }
+
+    Ok(())
+}
+
+/// Builds the record batch of the records of `table`.
+fn batch(tables: &Tables, table: Table) -> Result<RecordBatch> {
+    match table {
+        Table::Logs => logs_batch(&tables.logs),
+        Table::Events(EventKind::Swap) => swaps_batch(&tables.swaps),
+        Table::Events(EventKind::Mint) => mints_batch(&tables.mints),
+        Table::Events(EventKind::Burn) => burns_batch(&tables.burns),
+        Table::Events(EventKind::Flash) => flashes_batch(&tables.flashes),
+    }
+}
+
+fn logs_batch(logs: &[LogRecord]) -> Result<RecordBatch> {
+    let records: Vec<Record<()>> = logs.iter().map(|log| (log.clone(), ())).collect();
+
+    let mut fields = log_fields();
+    fields.push(Field::new("address", DataType::Utf8, true));
+
+    let mut columns = log_columns(&records);
+    columns.push(Arc::new(StringArray::from_iter(
+        logs.iter().map(|log| log.address.map(|a| format!("{a:?}"))),
+    )));
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+fn swaps_batch(records: &[Record<SwapFilter>]) -> Result<RecordBatch> {
+    let mut fields = log_fields();
+    fields.extend([
+        string_field("sender"),
+        string_field("recipient"),
+        string_field("amount0"),
+        string_field("amount1"),
+        string_field("sqrt_price_x96"),
+        string_field("liquidity"),
+        int_field("tick"),
+    ]);
+
+    let mut columns = log_columns(records);
+    columns.extend([
+        strings(records, |e| format!("{:?}", e.sender)),
+        strings(records, |e| format!("{:?}", e.recipient)),
+        strings(records, |e| e.amount_0.to_string()),
+        strings(records, |e| e.amount_1.to_string()),
+        strings(records, |e| e.sqrt_price_x96.to_string()),
+        strings(records, |e| e.liquidity.to_string()),
+        ints(records, |e| e.tick),
+    ]);
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+fn mints_batch(records: &[Record<MintFilter>]) -> Result<RecordBatch> {
+    let mut fields = log_fields();
+    fields.extend([
+        string_field("sender"),
+        string_field("owner"),
+        int_field("tick_lower"),
+        int_field("tick_upper"),
+        string_field("amount"),
+        string_field("amount0"),
+        string_field("amount1"),
+    ]);
+
+    let mut columns = log_columns(records);
+    columns.extend([
+        strings(records, |e| format!("{:?}", e.sender)),
+        strings(records, |e| format!("{:?}", e.owner)),
+        ints(records, |e| e.tick_lower),
+        ints(records, |e| e.tick_upper),
+        strings(records, |e| e.amount.to_string()),
+        strings(records, |e| e.amount_0.to_string()),
+        strings(records, |e| e.amount_1.to_string()),
+    ]);
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+fn burns_batch(records: &[Record<BurnFilter>]) -> Result<RecordBatch> {
+    let mut fields = log_fields();
+    fields.extend([
+        string_field("owner"),
+        int_field("tick_lower"),
+        int_field("tick_upper"),
+        string_field("amount"),
+        string_field("amount0"),
+        string_field("amount1"),
+    ]);
+
+    let mut columns = log_columns(records);
+    columns.extend([
+        strings(records, |e| format!("{:?}", e.owner)),
+        ints(records, |e| e.tick_lower),
+        ints(records, |e| e.tick_upper),
+        strings(records, |e| e.amount.to_string()),
+        strings(records, |e| e.amount_0.to_string()),
+        strings(records, |e| e.amount_1.to_string()),
+    ]);
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+fn flashes_batch(records: &[Record<FlashFilter>]) -> Result<RecordBatch> {
+    let mut fields = log_fields();
+    fields.extend([
+        string_field("sender"),
+        string_field("recipient"),
+        string_field("amount0"),
+        string_field("amount1"),
+        string_field("paid0"),
+        string_field("paid1"),
+    ]);
+
+    let mut columns = log_columns(records);
+    columns.extend([
+        strings(records, |e| format!("{:?}", e.sender)),
+        strings(records, |e| format!("{:?}", e.recipient)),
+        strings(records, |e| e.amount_0.to_string()),
+        strings(records, |e| e.amount_1.to_string()),
+        strings(records, |e| e.paid_0.to_string()),
+        strings(records, |e| e.paid_1.to_string()),
+    ]);
+
+    Ok(RecordBatch::try_new(
+        Arc::new(Schema::new(fields)),
+        columns,
+    )?)
+}
+
+/// The log metadata columns leading every table.
+fn log_fields() -> Vec<Field> {
+    vec![
+        Field::new("ethereum_log_id", DataType::Int32, true),
+        Field::new("block_number", DataType::UInt64, true),
+        Field::new("transaction_hash", DataType::Utf8, true),
+    ]
+}
+
+fn log_columns<T>(records: &[Record<T>]) -> Vec<ArrayRef> {
+    vec![
+        Arc::new(Int32Array::from_iter(
+            records.iter().map(|(log, _)| log.ethereum_log_id),
+        )),
+        Arc::new(UInt64Array::from_iter(
+            records.iter().map(|(log, _)| log.block_number),
+        )),
+        Arc::new(StringArray::from_iter(
+            records
+                .iter()
+                .map(|(log, _)| log.transaction_hash.map(|h| format!("{h:?}"))),
+        )),
+    ]
+}
+
+fn string_field(name: &str) -> Field {
+    Field::new(name, DataType::Utf8, false)
+}
+
+fn int_field(name: &str) -> Field {
+    Field::new(name, DataType::Int32, false)
+}
+
+fn strings<T>(records: &[Record<T>], f: impl Fn(&T) -> String) -> ArrayRef {
+    Arc::new(StringArray::from_iter_values(
+        records.iter().map(|(_, e)| f(e)),
+    ))
+}
+
+fn ints<T>(records: &[Record<T>], f: impl Fn(&T) -> i32) -> ArrayRef {
+    Arc::new(Int32Array::from_iter_values(
+        records.iter().map(|(_, e)| f(e)),
+    ))
+}
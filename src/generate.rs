@@ -0,0 +1,318 @@
+//! Generation of contract bindings, shared by the `generate` command and the build script, so
+//! it only depends on external crates.
+
+use ethers::prelude::Abigen;
+use eyre::{eyre, Result};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::{parse_quote, Item, ItemStruct};
+
+/// The directory generated bindings are written to by default.
+const DEFAULT_OUT: &str = "./bindings";
+
+/// The ABI file of the pool, whose bindings keep the name the indexer uses.
+const POOL_ABI: &str = "usdc_weth.abi";
+
+/// The name of the pool contract.
+pub const POOL_CONTRACT: &str = "USDC_WETH_POOL";
+
+/// The generated event structs stored by the indexer, with their renamed columns.
+const INDEXED_EVENTS: [(&str, &[(&str, &str)]); 4] = [
+    ("SwapFilter", &[("recipient", "receiver")]),
+    ("BurnFilter", &[]),
+    ("MintFilter", &[]),
+    ("FlashFilter", &[("recipient", "receiver")]),
+];
+
+/// The ABI of a contract, read from a bare ABI or a build artifact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAbi {
+    /// The name of the contract, used for its bindings.
+    pub name: String,
+
+    /// The JSON ABI of the contract.
+    pub abi: String,
+}
+
+/// Reads the ABI of a contract from a JSON file.
+///
+/// The file is either a bare ABI array, or a Foundry or Hardhat artifact with an `abi` field.
+/// The contract is named after the `contractName` of a Hardhat artifact, or else after the
+/// file, e.g. `MyToken` for `my_token.abi` or `ERC20` for `ERC20.json`. The pool ABI is named
+/// `USDC_WETH_POOL`.
+///
+/// # Arguments
+///
+/// - `path`: The ABI or artifact file.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the ABI, or an error if the file holds neither an ABI nor an
+/// artifact.
+pub fn read_abi(path: &Path) -> Result<ContractAbi> {
+    let json =
+        fs::read_to_string(path).map_err(|e| eyre!("failed to read {}: {e}", path.display()))?;
+    let value: Value =
+        serde_json::from_str(&json).map_err(|e| eyre!("invalid JSON {}: {e}", path.display()))?;
+
+    let abi = match &value {
+        Value::Array(_) => &value,
+        Value::Object(artifact) => match artifact.get("abi") {
+            Some(abi @ Value::Array(_)) => abi,
+            _ => return Err(eyre!("{} is not an ABI or an artifact", path.display())),
+        },
+        _ => return Err(eyre!("{} is not an ABI or an artifact", path.display())),
+    };
+
+    let file = path.file_name().unwrap_or_default().to_string_lossy();
+    let name = match value.get("contractName").and_then(Value::as_str) {
+        Some(name) => name.to_string(),
+        None if file == POOL_ABI => POOL_CONTRACT.to_string(),
+        None => contract_name(file.split('.').next().unwrap_or_default()),
+    };
+
+    Ok(ContractAbi {
+        name,
+        abi: abi.to_string(),
+    })
+}
+
+/// Turns a file name into a contract name, e.g. `UsdcWeth` for `usdc_weth`.
+fn contract_name(file: &str) -> String {
+    file.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Adds `#[derive(IndexedEvent)]` and its column attributes to the indexed event structs of
+/// generated bindings, since Abigen applies derives to every type.
+///
+/// # Arguments
+///
+/// - `source`: The generated bindings.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the annotated bindings, or an error if an event is missing.
+pub fn annotate(tokens: TokenStream) -> Result<TokenStream> {
+    let mut file: syn::File = syn::parse2(tokens)?;
+
+    for (name, renames) in INDEXED_EVENTS {
+        let missing = || eyre!("{} is missing from the bindings", name);
+
+        let item = find_struct(&mut file.items, name).ok_or_else(missing)?;
+        item.attrs
+            .push(parse_quote!(#[derive(eth_logs_derive::IndexedEvent)]));
+
+        for (field, column) in renames {
+            let field = item
+                .fields
+                .iter_mut()
+                .find(|f| f.ident.as_ref().is_some_and(|ident| ident == field))
+                .ok_or_else(missing)?;
+            field
+                .attrs
+                .push(parse_quote!(#[indexed_event(column = #column)]));
+        }
+    }
+
+    Ok(file.into_token_stream())
+}
+
+/// Finds a struct by name in a list of items and their inline modules.
+fn find_struct<'a>(items: &'a mut [Item], name: &str) -> Option<&'a mut ItemStruct> {
+    items.iter_mut().find_map(|item| match item {
+        Item::Struct(item) if item.ident == name => Some(item),
+        Item::Mod(module) => module
+            .content
+            .as_mut()
+            .and_then(|(_, items)| find_struct(items, name)),
+        _ => None,
+    })
+}
+
+/// Generates the bindings of a contract, with the derives of the pool bindings.
+///
+/// # Arguments
+///
+/// - `contract`: The ABI of the contract.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the name of the module of the bindings and their source.
+pub fn bindings(contract: &ContractAbi) -> Result<(String, String)> {
+    let mut bindings = Abigen::new(&contract.name, &contract.abi)?
+        .add_derive("serde::Serialize")?
+        .add_derive("serde::Deserialize")?
+        .generate()?;
+
+    if contract.name == POOL_CONTRACT {
+        bindings.tokens = annotate(bindings.tokens)?;
+    }
+
+    Ok((bindings.module_name(), bindings.to_string()))
+}
+
+/// Finds the ABI and artifact files of a directory and its subdirectories.
+///
+/// Hardhat debug files and build info are skipped, like JSON files without an ABI.
+fn find_abis(dir: &Path, found: &mut Vec<ContractAbi>) -> Result<()> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+
+    for path in entries {
+        let file = path.file_name().unwrap_or_default().to_string_lossy();
+
+        if path.is_dir() {
+            if file != "build-info" {
+                find_abis(&path, found)?;
+            }
+        } else if file.ends_with(".abi")
+            || (file.ends_with(".json") && !file.ends_with(".dbg.json"))
+        {
+            match read_abi(&path) {
+                Ok(contract) => found.push(contract),
+                Err(e) => println!("Skipping {}: {e}", path.display()),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates the bindings of every ABI and artifact of a directory.
+///
+/// # Arguments
+///
+/// - `dir`: The directory of ABIs.
+///
+/// # Returns
+///
+/// Returns a `Result` containing each contract with the module name and source of its bindings,
+/// or an error if two contracts share a module.
+pub fn module_bindings(dir: &Path) -> Result<Vec<(ContractAbi, String, String)>> {
+    let mut contracts = Vec::new();
+    find_abis(dir, &mut contracts)?;
+
+    let mut modules = HashSet::new();
+    let mut bindings = Vec::new();
+    for contract in contracts {
+        let (module, source) = self::bindings(&contract)?;
+        if !modules.insert(module.clone()) {
+            return Err(eyre!("several ABIs generate the module {module}"));
+        }
+        bindings.push((contract, module, source));
+    }
+
+    Ok(bindings)
+}
+
+/// Generates Rust bindings for a contract, or for every contract of a directory.
+///
+/// A single ABI is written to `out`, by default `./bindings/<contract>.rs`. A directory of ABIs
+/// is written as a module tree to the `out` directory, by default `./bindings`, with a `mod.rs`
+/// declaring a module per contract. The indexer itself builds against bindings generated by the
+/// build script, so these are only needed to commit or read them.
+///
+/// The migration creating the tables of each contract is written next to its bindings, as
+/// `<contract>.sql`.
+///
+/// # Arguments
+///
+/// - `abi`: An ABI or artifact file, or a directory of them.
+/// - `name`: The name of the contract of a single ABI, from the file by default.
+/// - `out`: The file or directory the bindings are written to.
+/// - `check`: Whether to only check that the written bindings are up to date, instead of
+///   writing them.
+/// - `schema`: Returns the migration of a contract.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the generation, or an error listing
+/// the stale files when checking.
+pub fn generate(
+    abi: &Path,
+    name: Option<&str>,
+    out: Option<&Path>,
+    check: bool,
+    schema: impl Fn(&ContractAbi) -> Result<String>,
+) -> Result<()> {
+    let mut files = Vec::new();
+
+    if abi.is_dir() {
+        if name.is_some() {
+            return Err(eyre!("--name only applies to a single ABI"));
+        }
+
+        let bindings = module_bindings(abi)?;
+        if bindings.is_empty() {
+            return Err(eyre!("no ABI found in {}", abi.display()));
+        }
+
+        // Each contract becomes a module named after it
+        let out = out.map_or_else(|| PathBuf::from(DEFAULT_OUT), Path::to_path_buf);
+        let mut modules = String::new();
+        for (contract, module, source) in bindings {
+            modules += &format!("#[allow(clippy::module_inception)]\npub mod {module};\n");
+            files.push((out.join(format!("{module}.rs")), source));
+            files.push((out.join(format!("{module}.sql")), schema(&contract)?));
+        }
+        files.push((out.join("mod.rs"), modules));
+    } else {
+        let mut contract = read_abi(abi)?;
+        if let Some(name) = name {
+            contract.name = name.to_string();
+        }
+
+        let (module, source) = bindings(&contract)?;
+        let out = out.map_or_else(
+            || Path::new(DEFAULT_OUT).join(format!("{module}.rs")),
+            Path::to_path_buf,
+        );
+        files.push((out.with_extension("sql"), schema(&contract)?));
+        files.push((out, source));
+    }
+
+    if check {
+        let stale: Vec<String> = files
+            .iter()
+            .filter(|(path, source)| fs::read_to_string(path).ok().as_ref() != Some(source))
+            .map(|(path, _)| path.display().to_string())
+            .collect();
+
+        return match stale.is_empty() {
+            true => {
+                println!("Types files are up to date");
+                Ok(())
+            }
+            false => Err(eyre!(
+                "stale types files, run generate to update them: {}",
+                stale.join(", ")
+            )),
+        };
+    }
+
+    for (path, source) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, source)?;
+    }
+
+    println!("Successfully created {} types files", files.len());
+
+    Ok(())
+}
@@ -0,0 +1,615 @@
+use ethers::{
+    abi::ParamType,
+    types::{Bytes, H160, I256, U256},
+};
+use eyre::Result;
+use futures::pin_mut;
+use postgres_types::{ToSql, Type};
+use std::collections::BTreeSet;
+use tokio_postgres::{binary_copy::BinaryCopyInWriter, Row};
+
+use crate::database::DB;
+use crate::error::IndexerError;
+use crate::types::pool_types::usdc_weth_pool::*;
+use crate::types::{Address, Wu128, WI256, WU256};
+
+/// The schemas of the indexed event tables.
+pub const SCHEMAS: [Schema; 4] = [
+    SwapFilter::SCHEMA,
+    BurnFilter::SCHEMA,
+    MintFilter::SCHEMA,
+    FlashFilter::SCHEMA,
+];
+
+/// An event stored in its own table, implemented with `#[derive(IndexedEvent)]`.
+pub trait IndexedEvent: Sized {
+    /// The table and columns of the event.
+    const SCHEMA: Schema;
+
+    /// The values of the event columns, in the order of `SCHEMA.columns`.
+    fn values(&self) -> Vec<Value>;
+
+    /// Reads the event from a row of its view, failing on a missing or malformed column.
+    fn from_row(row: &Row) -> Result<Self, IndexerError>;
+}
+
+/// A field type of the event structs generated by Abigen, stored in the column
+/// `ColumnKind::of` maps its Solidity type to.
+pub trait Stored: Sized {
+    /// The widest Solidity type generated as this type, e.g. `uint32` for `u32`.
+    const PARAM: &'static ParamType;
+
+    /// How the field is stored.
+    const KIND: ColumnKind = ColumnKind::of(Self::PARAM, false);
+
+    /// The value of the column.
+    fn value(&self) -> Value;
+
+    /// Reads the field from a row of its view.
+    ///
+    /// # Arguments
+    ///
+    /// - `row`: The row.
+    /// - `column`: The name of the column, or of the address role for addresses.
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError>;
+}
+
+/// Implements `Stored` for integers stored in an `INT` or `BIGINT` column.
+macro_rules! stored_int {
+    ($($ty:ty => $param:expr, $sql:ty;)*) => {$(
+        impl Stored for $ty {
+            const PARAM: &'static ParamType = &$param;
+
+            fn value(&self) -> Value {
+                Value::sql(*self as $sql)
+            }
+
+            fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+                let value: $sql = row.try_get(column)?;
+                Self::try_from(value).map_err(|e| IndexerError::Decode(Box::new(e)))
+            }
+        }
+    )*};
+}
+
+stored_int! {
+    i8 => ParamType::Int(8), i32;
+    i16 => ParamType::Int(16), i32;
+    i32 => ParamType::Int(32), i32;
+    i64 => ParamType::Int(64), i64;
+    u8 => ParamType::Uint(8), i32;
+    u16 => ParamType::Uint(16), i32;
+    u32 => ParamType::Uint(32), i64;
+}
+
+impl Stored for H160 {
+    const PARAM: &'static ParamType = &ParamType::Address;
+
+    fn value(&self) -> Value {
+        Value::Address(*self)
+    }
+
+    // Views expose addresses as `<column>_address`
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        Ok(*row.try_get::<_, Address>(format!("{column}_address").as_str())?)
+    }
+}
+
+impl Stored for I256 {
+    const PARAM: &'static ParamType = &ParamType::Int(256);
+
+    fn value(&self) -> Value {
+        Value::sql::<WI256>((*self).into())
+    }
+
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        Ok(*row.try_get::<_, WI256>(column)?)
+    }
+}
+
+impl Stored for U256 {
+    const PARAM: &'static ParamType = &ParamType::Uint(256);
+
+    fn value(&self) -> Value {
+        Value::sql::<WU256>((*self).into())
+    }
+
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        Ok(*row.try_get::<_, WU256>(column)?)
+    }
+}
+
+impl Stored for u128 {
+    const PARAM: &'static ParamType = &ParamType::Uint(128);
+
+    fn value(&self) -> Value {
+        Value::sql::<Wu128>((*self).into())
+    }
+
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        Ok(*row.try_get::<_, Wu128>(column)?)
+    }
+}
+
+// A uint64 does not fit a BIGINT, so it shares the 16 byte column of wider integers
+impl Stored for u64 {
+    const PARAM: &'static ParamType = &ParamType::Uint(64);
+
+    fn value(&self) -> Value {
+        Value::sql::<Wu128>((*self as u128).into())
+    }
+
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        let value: u128 = *row.try_get::<_, Wu128>(column)?;
+        Self::try_from(value).map_err(|e| IndexerError::Decode(Box::new(e)))
+    }
+}
+
+impl Stored for bool {
+    const PARAM: &'static ParamType = &ParamType::Bool;
+
+    fn value(&self) -> Value {
+        Value::sql(*self)
+    }
+
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        Ok(row.try_get(column)?)
+    }
+}
+
+impl Stored for String {
+    const PARAM: &'static ParamType = &ParamType::String;
+
+    fn value(&self) -> Value {
+        Value::sql(self.clone())
+    }
+
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        Ok(row.try_get(column)?)
+    }
+}
+
+impl Stored for Bytes {
+    const PARAM: &'static ParamType = &ParamType::Bytes;
+
+    fn value(&self) -> Value {
+        Value::sql(self.to_vec())
+    }
+
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        Ok(row.try_get::<_, Vec<u8>>(column)?.into())
+    }
+}
+
+impl<const N: usize> Stored for [u8; N] {
+    const PARAM: &'static ParamType = &ParamType::FixedBytes(N);
+
+    fn value(&self) -> Value {
+        Value::sql(self.to_vec())
+    }
+
+    fn read(row: &Row, column: &str) -> Result<Self, IndexerError> {
+        let raw: Vec<u8> = row.try_get(column)?;
+        let actual = raw.len();
+        raw.try_into().map_err(|_| IndexerError::InvalidValue {
+            name: "bytes",
+            expected: N,
+            actual,
+        })
+    }
+}
+
+/// How a column is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    /// An address, stored as an id into the `addresses` table.
+    Address,
+
+    /// A signed 256-bit integer, stored as 32 big endian bytes.
+    Int256,
+
+    /// An unsigned 256-bit integer, stored as 32 big endian bytes.
+    Uint256,
+
+    /// An unsigned 128-bit integer, stored as 16 big endian bytes.
+    Uint128,
+
+    /// An integer of up to 24 bits, such as a tick.
+    Int,
+
+    /// An integer of up to 64 bits.
+    BigInt,
+
+    /// A boolean.
+    Bool,
+
+    /// Dynamic bytes.
+    Bytes,
+
+    /// A string.
+    Text,
+}
+
+impl ColumnKind {
+    /// The column of a Solidity value, shared by the derived event tables and the contracts
+    /// indexed by ABI.
+    ///
+    /// # Arguments
+    ///
+    /// - `param`: The Solidity type of the value.
+    /// - `indexed`: Whether the value is an indexed event input, which only keeps the hash of
+    ///   dynamic values.
+    pub const fn of(param: &ParamType, indexed: bool) -> Self {
+        match param {
+            ParamType::String
+            | ParamType::Bytes
+            | ParamType::Array(_)
+            | ParamType::FixedArray(..)
+            | ParamType::Tuple(_)
+                if indexed =>
+            {
+                Self::Bytes
+            }
+            ParamType::Address => Self::Address,
+            ParamType::Int(bits) if *bits <= 32 => Self::Int,
+            ParamType::Int(bits) if *bits <= 64 => Self::BigInt,
+            ParamType::Int(_) => Self::Int256,
+            ParamType::Uint(bits) if *bits <= 24 => Self::Int,
+            ParamType::Uint(bits) if *bits <= 32 => Self::BigInt,
+            ParamType::Uint(bits) if *bits <= 128 => Self::Uint128,
+            ParamType::Uint(_) => Self::Uint256,
+            ParamType::Bool => Self::Bool,
+            ParamType::String => Self::Text,
+            ParamType::Bytes | ParamType::FixedBytes(_) => Self::Bytes,
+            // Arrays and tuples are stored as JSON
+            ParamType::Array(_) | ParamType::FixedArray(..) | ParamType::Tuple(_) => Self::Text,
+        }
+    }
+
+    /// The type and constraints of the column in `CREATE TABLE`.
+    pub fn column_type(&self) -> &'static str {
+        match self {
+            Self::Address => "INT NOT NULL REFERENCES addresses (id)",
+            Self::Int => "INT NOT NULL",
+            Self::BigInt => "BIGINT NOT NULL",
+            Self::Bool => "BOOLEAN NOT NULL",
+            Self::Text => "TEXT NOT NULL",
+            Self::Int256 | Self::Uint256 | Self::Uint128 | Self::Bytes => "BYTEA NOT NULL",
+        }
+    }
+
+    /// The PostgreSQL type of the column.
+    pub fn sql_type(&self) -> Type {
+        match self {
+            Self::Address | Self::Int => Type::INT4,
+            Self::BigInt => Type::INT8,
+            Self::Bool => Type::BOOL,
+            Self::Text => Type::TEXT,
+            Self::Int256 | Self::Uint256 | Self::Uint128 | Self::Bytes => Type::BYTEA,
+        }
+    }
+
+    /// The type and constraints of the column in a SQLite `CREATE TABLE`, where addresses are
+    /// stored as their bytes.
+    pub fn sqlite_type(&self) -> &'static str {
+        match self {
+            Self::Int | Self::BigInt | Self::Bool => "INTEGER NOT NULL",
+            Self::Text => "TEXT NOT NULL",
+            Self::Address | Self::Int256 | Self::Uint256 | Self::Uint128 | Self::Bytes => {
+                "BLOB NOT NULL"
+            }
+        }
+    }
+}
+
+/// A column of an event table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    /// The name of the column, or of the address role for addresses.
+    pub name: &'static str,
+
+    /// How the column is stored.
+    pub kind: ColumnKind,
+
+    /// Whether the input is indexed in the topics, which indexes the column.
+    pub indexed: bool,
+}
+
+impl Column {
+    /// The name of the column in the table, `<name>_id` for addresses.
+    pub fn stored_name(&self) -> String {
+        match self.kind {
+            ColumnKind::Address => format!("{}_id", self.name),
+            _ => self.name.to_string(),
+        }
+    }
+
+    /// The name of the column in a SQLite table, `<name>_address` for addresses.
+    pub fn sqlite_name(&self) -> String {
+        match self.kind {
+            ColumnKind::Address => format!("{}_address", self.name),
+            _ => self.name.to_string(),
+        }
+    }
+
+    /// The definition of the column in `CREATE TABLE`.
+    fn definition(&self) -> String {
+        format!("{} {}", self.stored_name(), self.kind.column_type())
+    }
+}
+
+/// The table of an event type and its event columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Schema {
+    pub table: &'static str,
+    pub columns: &'static [Column],
+}
+
+impl Schema {
+    /// The view presenting the table with its addresses, e.g. `swap_events` for `swap_logs`.
+    pub fn view(&self) -> String {
+        format!("{}_events", self.table.trim_end_matches("_logs"))
+    }
+
+    /// The roles of the address columns.
+    pub fn address_columns(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.columns
+            .iter()
+            .filter(|c| c.kind == ColumnKind::Address)
+            .map(|c| c.name)
+    }
+
+    /// Returns the statement creating the table, if it does not already exist.
+    ///
+    /// # Arguments
+    ///
+    /// - `partitioned`: Whether the table is partitioned by block range.
+    pub fn create_table(&self, partitioned: bool) -> String {
+        let definitions: Vec<String> = self.columns.iter().map(Column::definition).collect();
+        event_table(self.table, &definitions, partitioned)
+    }
+
+    /// Returns the statements indexing the `ethereum_logs` foreign key and the columns of the
+    /// inputs indexed in the topics, if they do not already exist.
+    pub fn create_indexes(&self) -> String {
+        let table = self.table;
+        let mut sql = format!(
+            "
+            CREATE INDEX IF NOT EXISTS {table}_ethereum_log_id_idx ON {table} (ethereum_log_id);"
+        );
+
+        for column in self.columns.iter().filter(|c| c.indexed) {
+            sql += &format!(
+                "
+            CREATE INDEX IF NOT EXISTS {table}_{}_idx ON {table} ({});",
+                column.name,
+                column.stored_name()
+            );
+        }
+
+        sql + "\n"
+    }
+}
+
+/// Returns the statement creating an event table, if it does not already exist.
+///
+/// Every event table starts with its id, the id of its `ethereum_logs` row and its block
+/// number, and ends with the indexing time. Partitioned tables are range partitioned on
+/// the block number and have no foreign key to `ethereum_logs`.
+///
+/// # Arguments
+///
+/// - `table`: The name of the table.
+/// - `definitions`: The definitions of the event columns.
+/// - `partitioned`: Whether the table is partitioned by block range.
+pub(crate) fn event_table(table: &str, definitions: &[String], partitioned: bool) -> String {
+    let columns: String = definitions
+        .iter()
+        .map(|d| format!("\n                {d},"))
+        .collect();
+
+    match partitioned {
+        false => format!(
+            "
+            CREATE TABLE IF NOT EXISTS {table} (
+                id SERIAL PRIMARY KEY,
+                ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,
+                block_number BYTEA,{columns}
+                timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL
+            );
+            "
+        ),
+        true => format!(
+            "
+            CREATE TABLE IF NOT EXISTS {table} (
+                id SERIAL,
+                ethereum_log_id INT,
+                block_number BYTEA NOT NULL,{columns}
+                timestamp TIMESTAMPTZ DEFAULT NOW() NOT NULL,
+                PRIMARY KEY (id, block_number)
+            ) PARTITION BY RANGE (block_number);
+            "
+        ),
+    }
+}
+
+/// A column value both backends can write.
+pub trait SqlValue: ToSql + rusqlite::ToSql + Sync + Send {
+    /// The value as a PostgreSQL parameter.
+    fn postgres(&self) -> &(dyn ToSql + Sync);
+
+    /// The value as a SQLite parameter.
+    fn sqlite(&self) -> &dyn rusqlite::ToSql;
+}
+
+impl<T: ToSql + rusqlite::ToSql + Sync + Send> SqlValue for T {
+    fn postgres(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn sqlite(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+}
+
+/// The value of an event column.
+pub enum Value {
+    /// An address, written as its id in PostgreSQL and as its bytes in SQLite.
+    Address(H160),
+
+    /// Any other value, written as is.
+    Sql(Box<dyn SqlValue>),
+}
+
+impl Value {
+    /// Wraps a value written as is.
+    pub fn sql<T: SqlValue + 'static>(value: T) -> Self {
+        Self::Sql(Box::new(value))
+    }
+}
+
+impl DB {
+    /// Resolves the values of an event, looking up the ids of its addresses.
+    async fn resolve(&self, values: Vec<Value>) -> Result<Vec<Box<dyn SqlValue>>> {
+        let mut resolved = Vec::new();
+
+        for value in values {
+            resolved.push(match value {
+                Value::Address(address) => Box::new(self.address_id(address).await?) as _,
+                Value::Sql(value) => value,
+            });
+        }
+
+        Ok(resolved)
+    }
+
+    /// Inserts an event into its table.
+    ///
+    /// # Arguments
+    ///
+    /// - `ethereum_log_id`: The id of the `ethereum_logs` row the event belongs to, if any.
+    /// - `block_number`: The number of the block containing the log, if known.
+    /// - `event`: The event to insert.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the id of the inserted row.
+    pub async fn insert_indexed<T: IndexedEvent>(
+        &self,
+        ethereum_log_id: Option<i32>,
+        block_number: Option<u64>,
+        event: &T,
+    ) -> Result<i32> {
+        let schema = T::SCHEMA;
+        let names: Vec<String> = schema.columns.iter().map(Column::stored_name).collect();
+
+        self.insert_values(
+            schema.table,
+            &names,
+            ethereum_log_id,
+            block_number,
+            event.values(),
+        )
+        .await
+    }
+
+    /// Writes events of one type with a single binary `COPY`, much faster than row by row
+    /// inserts for large batches.
+    ///
+    /// The partitions of the blocks are created first if needed, and the ids of the addresses
+    /// are looked up before the copy starts.
+    ///
+    /// # Arguments
+    ///
+    /// - `events`: The events, each with the id of its `ethereum_logs` row and its block number.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of written rows.
+    pub async fn copy_events<T: IndexedEvent>(&self, events: &[(i32, u64, T)]) -> Result<u64> {
+        let schema = T::SCHEMA;
+        let names: Vec<String> = schema.columns.iter().map(Column::stored_name).collect();
+        let mut types = vec![Type::INT4, Type::BYTEA];
+        types.extend(schema.columns.iter().map(|c| c.kind.sql_type()));
+
+        let blocks: BTreeSet<u64> = events.iter().map(|(_, block, _)| *block).collect();
+        for block_number in blocks {
+            self.ensure_partition(block_number).await?;
+        }
+
+        let mut rows = Vec::with_capacity(events.len());
+        for (ethereum_log_id, block_number, event) in events {
+            rows.push((
+                *ethereum_log_id,
+                block_number.to_be_bytes().to_vec(),
+                self.resolve(event.values()).await?,
+            ));
+        }
+
+        let sink = self
+            .client
+            .copy_in(&format!(
+                "COPY {} (ethereum_log_id, block_number, {}) FROM STDIN BINARY",
+                schema.table,
+                names.join(", ")
+            ))
+            .await?;
+        let writer = BinaryCopyInWriter::new(sink, &types);
+        pin_mut!(writer);
+
+        for (ethereum_log_id, block_number, values) in &rows {
+            let mut row: Vec<&(dyn ToSql + Sync)> = vec![ethereum_log_id, block_number];
+            row.extend(values.iter().map(|v| v.postgres()));
+            writer.as_mut().write(&row).await?;
+        }
+
+        Ok(writer.finish().await?)
+    }
+
+    /// Inserts the values of an event into a table, with the block number of its log.
+    ///
+    /// # Arguments
+    ///
+    /// - `table`: The event table.
+    /// - `names`: The stored names of the event columns.
+    /// - `ethereum_log_id`: The id of the `ethereum_logs` row the event belongs to, if any.
+    /// - `block_number`: The number of the block containing the log, if known.
+    /// - `values`: The values of the event columns, in the order of `names`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the id of the inserted row.
+    pub(crate) async fn insert_values(
+        &self,
+        table: &str,
+        names: &[String],
+        ethereum_log_id: Option<i32>,
+        block_number: Option<u64>,
+        values: Vec<Value>,
+    ) -> Result<i32> {
+        let placeholders: Vec<String> = (3..names.len() + 3).map(|i| format!("${i}")).collect();
+
+        let block_number = block_number.map(|n| n.to_be_bytes().to_vec());
+        let values = self.resolve(values).await?;
+        let mut params: Vec<&(dyn ToSql + Sync)> = vec![&ethereum_log_id, &block_number];
+        params.extend(values.iter().map(|v| v.postgres()));
+
+        let row = self
+            .client
+            .query_one(
+                &format!(
+                    "
+                   INSERT INTO {table} (ethereum_log_id, block_number, {})
+                   VALUES ($1, $2, {})
+                   RETURNING id
+                   ",
+                    names.join(", "),
+                    placeholders.join(", ")
+                ),
+                &params,
+            )
+            .await?;
+
+        Ok(row.get("id"))
+    }
+}
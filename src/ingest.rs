@@ -0,0 +1,352 @@
+use async_trait::async_trait;
+use ethers::{
+    providers::{Middleware, Provider, StreamExt, Ws},
+    types::{Filter, Log, H160, H256},
+};
+use eyre::{eyre, Result};
+use futures::{
+    future,
+    stream::{self, BoxStream},
+    Stream,
+};
+use log::info;
+use std::collections::{HashMap, HashSet};
+
+use crate::config::{IngestSettings, Secret, Settings};
+use crate::error::IndexerError;
+use crate::types::pool_types::USDC_WETH_POOL_ABI;
+
+/// The logs of the indexed contracts, and how they are read from the node.
+#[derive(Debug, Clone)]
+pub struct Source {
+    /// The WebSocket URL of the node.
+    url: Secret,
+
+    /// The chain id the node must serve.
+    chain_id: Option<u64>,
+
+    /// The filter matching the logs of every contract.
+    filter: Filter,
+
+    /// The first block and the selectors of the allowed events of each contract.
+    contracts: HashMap<H160, (u64, HashSet<H256>)>,
+
+    /// The earliest start block of the contracts, if any has one.
+    start_block: Option<u64>,
+
+    /// Batching, concurrency and confirmations of the requests.
+    ingest: IngestSettings,
+}
+
+/// The allowed logs of a block range, fetched by a single request.
+#[derive(Debug, Clone)]
+pub struct Batch {
+    /// The first block of the range.
+    pub from: u64,
+
+    /// The last block of the range.
+    pub to: u64,
+
+    /// The logs of the range, in block order.
+    pub logs: Vec<Log>,
+}
+
+/// The logs to index, in block order.
+pub struct Follow<'a> {
+    /// Batches of logs, each covering whole blocks.
+    pub batches: BoxStream<'a, Result<Batch>>,
+
+    /// The logs of new blocks, streamed as they arrive once the batches are indexed. Only set
+    /// without confirmations, as new blocks are otherwise fetched in batches.
+    pub live: Option<BoxStream<'a, Result<Log>>>,
+}
+
+/// Reads the timestamps of blocks, which time-based rollups, exports and retention use.
+#[async_trait]
+pub trait BlockTimes: Send + Sync {
+    /// Reads the timestamp of a block in seconds since the epoch, or `None` if it is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_hash`: The hash of the block.
+    async fn block_time(&self, block_hash: H256) -> Result<Option<u64>>;
+}
+
+#[async_trait]
+impl BlockTimes for Provider<Ws> {
+    async fn block_time(&self, block_hash: H256) -> Result<Option<u64>> {
+        let block = self
+            .get_block(block_hash)
+            .await
+            .map_err(IndexerError::from)?;
+        Ok(block.map(|b| b.timestamp.as_u64()))
+    }
+}
+
+/// Events that do not come from a node have no block times.
+#[async_trait]
+impl BlockTimes for () {
+    async fn block_time(&self, _block_hash: H256) -> Result<Option<u64>> {
+        Ok(None)
+    }
+}
+
+impl Source {
+    /// Creates the source of the logs of a set of contracts.
+    ///
+    /// # Arguments
+    ///
+    /// - `settings`: The settings of the indexer.
+    /// - `contracts`: The address, start block and selectors of the allowed events of each
+    ///   contract.
+    pub fn new(
+        settings: &Settings,
+        contracts: impl IntoIterator<Item = (H160, Option<u64>, Vec<H256>)>,
+    ) -> Self {
+        let contracts: Vec<_> = contracts.into_iter().collect();
+
+        let addresses: Vec<H160> = contracts.iter().map(|(address, ..)| *address).collect();
+        let selectors: HashSet<H256> = contracts
+            .iter()
+            .flat_map(|(_, _, selectors)| selectors.clone())
+            .collect();
+        let filter = Filter::new()
+            .address(addresses)
+            .topic0(selectors.into_iter().collect::<Vec<_>>());
+
+        Self {
+            url: settings.rpc.url.clone(),
+            chain_id: settings.chain_id,
+            filter,
+            start_block: contracts.iter().filter_map(|(_, start, _)| *start).min(),
+            contracts: contracts
+                .into_iter()
+                .map(|(address, start, selectors)| {
+                    (
+                        address,
+                        (start.unwrap_or(0), selectors.into_iter().collect()),
+                    )
+                })
+                .collect(),
+            ingest: settings.ingest.clone(),
+        }
+    }
+
+    /// Creates the source of the logs of the pools, restricted to their allowed events.
+    ///
+    /// # Arguments
+    ///
+    /// - `settings`: The settings of the indexer.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the source, or an error if contracts are indexed by ABI.
+    pub fn pool(settings: &Settings) -> Result<Self> {
+        if settings.by_abi() {
+            return Err(eyre!(
+                "only the pool is supported, not contracts indexed by ABI"
+            ));
+        }
+
+        let contracts = settings
+            .contracts
+            .iter()
+            .map(|contract| {
+                let selectors = USDC_WETH_POOL_ABI
+                    .events()
+                    .filter(|e| contract.events.is_empty() || contract.events.contains(&e.name))
+                    .map(|e| e.signature())
+                    .collect();
+                Ok((contract.address()?, contract.start_block, selectors))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::new(settings, contracts))
+    }
+
+    /// The number of blocks fetched by a single request.
+    pub fn batch_size(&self) -> u64 {
+        self.ingest.batch_size
+    }
+
+    /// Connects to the node, checking that it serves the configured chain.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the provider, or an error if the node serves another chain.
+    pub async fn connect(&self) -> Result<Provider<Ws>> {
+        let provider = Provider::<Ws>::connect(self.url.expose()).await?;
+
+        if let Some(expected) = self.chain_id {
+            let actual = provider.get_chainid().await?;
+            if actual.as_u64() != expected {
+                return Err(eyre!(
+                    "the node serves chain {actual}, but chain_id is {expected}"
+                ));
+            }
+        }
+
+        Ok(provider)
+    }
+
+    /// Whether a log belongs to an allowed event of a contract, at or after its start block.
+    pub fn allows(&self, log: &Log) -> bool {
+        let Some((start_block, selectors)) = self.contracts.get(&log.address) else {
+            return false;
+        };
+
+        log.block_number.is_none_or(|b| b.as_u64() >= *start_block)
+            && log.topics.first().is_some_and(|t| selectors.contains(t))
+    }
+
+    /// The first block to fetch on startup, after the checkpoint and no earlier than the
+    /// earliest start block, or `None` to only follow new blocks.
+    ///
+    /// # Arguments
+    ///
+    /// - `checkpoint`: The last committed block of the storage backend.
+    pub fn start(&self, checkpoint: Option<u64>) -> Option<u64> {
+        let start = self.start_block?;
+        Some(checkpoint.map_or(start, |c| start.max(c + 1)))
+    }
+
+    /// Fetches the allowed logs of a block range through `eth_getLogs`, as batches of
+    /// `batch_size` blocks with up to `concurrency` requests in flight.
+    ///
+    /// # Arguments
+    ///
+    /// - `provider`: The node.
+    /// - `from`: The first block to fetch.
+    /// - `to`: The last block to fetch.
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of the batches, in block order.
+    pub fn batches<'a>(
+        &'a self,
+        provider: &'a Provider<Ws>,
+        from: u64,
+        to: u64,
+    ) -> impl Stream<Item = Result<Batch>> + Send + 'a {
+        let ranges = (from..=to)
+            .step_by(self.ingest.batch_size as usize)
+            .map(move |start| (start, to.min(start + self.ingest.batch_size - 1)));
+
+        stream::iter(ranges)
+            .map(move |(start, end)| async move {
+                let filter = self.filter.clone().from_block(start).to_block(end);
+                let logs = provider
+                    .get_logs(&filter)
+                    .await
+                    .map_err(IndexerError::from)?;
+
+                info!("Fetched logs for blocks {} to {}", start, end);
+                Ok(Batch {
+                    from: start,
+                    to: end,
+                    logs: logs.into_iter().filter(|log| self.allows(log)).collect(),
+                })
+            })
+            .buffered(self.ingest.concurrency)
+    }
+
+    /// Fetches the allowed logs of a block range through `eth_getLogs`.
+    ///
+    /// # Arguments
+    ///
+    /// - `provider`: The node.
+    /// - `from`: The first block to fetch.
+    /// - `to`: The last block to fetch.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the logs of the range, in block order.
+    pub async fn fetch(&self, provider: &Provider<Ws>, from: u64, to: u64) -> Result<Vec<Log>> {
+        let mut logs = Vec::new();
+
+        let mut batches = Box::pin(self.batches(provider, from, to));
+        while let Some(batch) = batches.next().await {
+            logs.extend(batch?.logs);
+        }
+
+        Ok(logs)
+    }
+
+    /// Follows the logs of the contracts from the node.
+    ///
+    /// The blocks from `start` to the head are fetched first. Without confirmations, the logs
+    /// of new blocks are then streamed as they arrive, subscribing before reading the head so
+    /// no block is missed. With confirmations, every new head instead fetches the blocks that
+    /// reached `confirmations` blocks on top of them.
+    ///
+    /// # Arguments
+    ///
+    /// - `provider`: The node.
+    /// - `checkpoint`: The last committed block of the storage backend.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the logs to index.
+    pub async fn follow<'a>(
+        &'a self,
+        provider: &'a Provider<Ws>,
+        checkpoint: Option<u64>,
+    ) -> Result<Follow<'a>> {
+        let confirmations = self.ingest.confirmations;
+        let start = self.start(checkpoint);
+
+        if confirmations == 0 {
+            let live = provider.subscribe_logs(&self.filter).await?;
+            let head = provider.get_block_number().await?.as_u64();
+
+            let batches = match start {
+                Some(from) => self.batches(provider, from, head).boxed(),
+                None => stream::empty().boxed(),
+            };
+
+            // Blocks up to the head are part of the batches
+            let live = live
+                .filter(move |log| {
+                    future::ready(
+                        self.allows(log)
+                            && (start.is_none()
+                                || log.block_number.is_none_or(|b| b.as_u64() > head)),
+                    )
+                })
+                .map(Ok)
+                .boxed();
+
+            return Ok(Follow {
+                batches,
+                live: Some(live),
+            });
+        }
+
+        let heads = provider.subscribe_blocks().await?;
+        let head = provider.get_block_number().await?.as_u64();
+        let confirmed = head.saturating_sub(confirmations);
+        let from = start.unwrap_or(confirmed + 1);
+
+        // A head without a number ends the stream with an error
+        let live = stream::unfold(
+            Some((heads, from.max(confirmed + 1))),
+            move |state| async move {
+                let (mut heads, next) = state?;
+                let Some(head) = heads.next().await?.number else {
+                    let error = eyre!("the node sent a head without a number");
+                    return Some((stream::once(future::err(error)).boxed(), None));
+                };
+
+                let confirmed = head.as_u64().saturating_sub(confirmations);
+                let batches = self.batches(provider, next, confirmed).boxed();
+                Some((batches, Some((heads, next.max(confirmed + 1)))))
+            },
+        )
+        .flatten();
+
+        Ok(Follow {
+            batches: self.batches(provider, from, confirmed).chain(live).boxed(),
+            live: None,
+        })
+    }
+}
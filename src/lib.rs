@@ -1,6 +1,27 @@
+// Lets `#[derive(IndexedEvent)]`, which names its items by `::eth_logs` paths, be used inside
+// this crate too
+extern crate self as eth_logs;
+
+pub mod addresses;
 pub mod command;
 pub mod config;
 pub mod constants;
+pub mod coverage;
 pub mod database;
+pub mod dead_letter;
 pub mod decode;
+pub mod dynamic;
+pub mod envelope;
+pub mod error;
+pub mod export;
+pub mod generate;
+pub mod indexed;
+pub mod ingest;
+pub mod notify;
+pub mod query;
+pub mod redecode;
+pub mod retention;
+pub mod rollup;
+pub mod storage;
 pub mod types;
+pub mod verify;
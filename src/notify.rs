@@ -0,0 +1,74 @@
+use ethers::prelude::LogMeta;
+use eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::database::DB;
+use crate::envelope::Envelope;
+use crate::types::pool_types::usdc_weth_pool::USDC_WETH_POOLEvents;
+
+/// The prefix of the notification channels, followed by the event type, e.g. `ethlog_swap`.
+pub const CHANNEL_PREFIX: &str = "ethlog_";
+
+/// The payload sent on the channel of an event type for every committed event: the id of the
+/// event row, followed by the fields of its [`Envelope`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notification {
+    /// The id of the event row in its table.
+    pub id: i32,
+
+    /// The event with its log metadata.
+    #[serde(flatten)]
+    pub envelope: Envelope,
+}
+
+impl Notification {
+    /// Builds the notification of an event row.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: LogMeta of the log the event was decoded from.
+    /// - `event`: The decoded event.
+    /// - `id`: The id of the event row.
+    ///
+    /// # Returns
+    ///
+    /// Returns the notification, or `None` for events that are not indexed.
+    pub fn new(meta: &LogMeta, event: &USDC_WETH_POOLEvents, id: i32) -> Option<Self> {
+        let envelope = Envelope::new(meta.clone(), event.clone())?;
+
+        Some(Self { id, envelope })
+    }
+
+    /// The channel the notification is sent on.
+    pub fn channel(&self) -> String {
+        format!("{CHANNEL_PREFIX}{}", self.envelope.kind().name())
+    }
+}
+
+impl DB {
+    /// Sends a notification to the listeners of its channel.
+    ///
+    /// PostgreSQL delivers notifications when the transaction commits and drops them on
+    /// rollback, so listeners only hear about committed events.
+    ///
+    /// # Arguments
+    ///
+    /// - `notification`: The notification to send.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the notification.
+    pub async fn notify(&self, notification: &Notification) -> Result<()> {
+        self.client
+            .execute(
+                "SELECT pg_notify($1, $2)",
+                &[
+                    &notification.channel(),
+                    &serde_json::to_string(notification)?,
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,392 @@
+use ethers::types::{H160, I256, U256};
+use eyre::{eyre, Result};
+use postgres_types::ToSql;
+use std::time::SystemTime;
+
+use crate::database::DB;
+use crate::error::IndexerError;
+use crate::export::{EventKind, LogRecord};
+use crate::indexed::IndexedEvent;
+use crate::types::pool_types::usdc_weth_pool::*;
+
+/// Filters and pagination for the typed event queries on `DB`.
+///
+/// Every filter is optional and they are combined with AND. Results are ordered by the id of
+/// the event row, and `after` continues from the cursor of a previous page.
+#[derive(Debug, Clone)]
+pub struct EventQuery {
+    /// Only return events emitted by this pool.
+    pub pool: Option<H160>,
+
+    /// The first block to return.
+    pub from_block: Option<u64>,
+
+    /// The last block to return.
+    pub to_block: Option<u64>,
+
+    /// The earliest block time to return.
+    pub from_time: Option<SystemTime>,
+
+    /// The latest block time to return.
+    pub to_time: Option<SystemTime>,
+
+    /// Only return events where any address column, such as the sender, recipient or owner,
+    /// equals this address.
+    pub address: Option<H160>,
+
+    /// The smallest `amount0` to return.
+    pub min_amount0: Option<I256>,
+
+    /// The largest `amount0` to return.
+    pub max_amount0: Option<I256>,
+
+    /// The smallest `amount1` to return.
+    pub min_amount1: Option<I256>,
+
+    /// The largest `amount1` to return.
+    pub max_amount1: Option<I256>,
+
+    /// The lowest tick. Swaps match on their tick, positions on their lower tick.
+    pub tick_lower: Option<i32>,
+
+    /// The highest tick. Swaps match on their tick, positions on their upper tick.
+    pub tick_upper: Option<i32>,
+
+    /// Continue after the last event of a previous page.
+    pub after: Option<Cursor>,
+
+    /// The maximum number of events per page, greater than zero.
+    pub limit: u32,
+}
+
+impl Default for EventQuery {
+    fn default() -> Self {
+        Self {
+            pool: None,
+            from_block: None,
+            to_block: None,
+            from_time: None,
+            to_time: None,
+            address: None,
+            min_amount0: None,
+            max_amount0: None,
+            min_amount1: None,
+            max_amount1: None,
+            tick_lower: None,
+            tick_upper: None,
+            after: None,
+            limit: 1000,
+        }
+    }
+}
+
+/// A keyset pagination cursor, pointing at the last event of a page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor(pub i32);
+
+/// An event read back from the database, with its row id and log metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Indexed<T> {
+    /// The id of the event row.
+    pub id: i32,
+
+    /// The log metadata of the event.
+    pub log: LogRecord,
+
+    /// The decoded event.
+    pub event: T,
+}
+
+/// A page of events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    /// The events of the page, ordered by id.
+    pub items: Vec<Indexed<T>>,
+
+    /// The cursor of the next page, or `None` if this is the last one.
+    pub next: Option<Cursor>,
+}
+
+/// How the amount columns of an event table are encoded.
+#[derive(Clone, Copy)]
+enum Amounts {
+    /// Two's complement I256.
+    Signed,
+
+    /// Unsigned U256.
+    Unsigned,
+}
+
+/// How the tick columns of an event table are laid out.
+#[derive(Clone, Copy)]
+enum Ticks {
+    /// A single `tick` column.
+    Single,
+
+    /// A `tick_lower` and `tick_upper` column.
+    Range,
+
+    /// No tick column.
+    None,
+}
+
+impl EventQuery {
+    /// Builds the query of an event table, joined as `e` with `ethereum_logs` as `l`.
+    ///
+    /// One row more than `limit` is selected, to know whether a next page exists.
+    ///
+    /// # Arguments
+    ///
+    /// - `kind`: The event table to read.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the statement and its parameters, or an error if the
+    /// limit is zero or the table lacks a filtered column.
+    pub fn sql(&self, kind: EventKind) -> Result<(String, Vec<Box<dyn ToSql + Sync>>)> {
+        if self.limit == 0 {
+            return Err(eyre!("limit must be greater than zero"));
+        }
+
+        let (address_columns, amounts, ticks) = match kind {
+            EventKind::Swap => (
+                ["sender_address", "receiver_address"].as_slice(),
+                Amounts::Signed,
+                Ticks::Single,
+            ),
+            EventKind::Mint => (
+                ["sender_address", "owner_address"].as_slice(),
+                Amounts::Unsigned,
+                Ticks::Range,
+            ),
+            EventKind::Burn => (
+                ["owner_address"].as_slice(),
+                Amounts::Unsigned,
+                Ticks::Range,
+            ),
+            EventKind::Flash => (
+                ["sender_address", "receiver_address"].as_slice(),
+                Amounts::Unsigned,
+                Ticks::None,
+            ),
+        };
+
+        let mut conditions: Vec<String> = Vec::new();
+        let mut params: Vec<Box<dyn ToSql + Sync>> = Vec::new();
+
+        let mut push = |condition: String, param: Box<dyn ToSql + Sync>| {
+            params.push(param);
+            conditions.push(condition.replace('?', &format!("${}", params.len())));
+        };
+
+        if let Some(pool) = self.pool {
+            push("l.address = ?".into(), Box::new(pool.as_bytes().to_vec()));
+        }
+        if let Some(block) = self.from_block {
+            push(
+                "l.block_number >= ?".into(),
+                Box::new(block.to_be_bytes().to_vec()),
+            );
+        }
+        if let Some(block) = self.to_block {
+            push(
+                "l.block_number <= ?".into(),
+                Box::new(block.to_be_bytes().to_vec()),
+            );
+        }
+        // Block times only increase with the block number
+        if let Some(time) = self.from_time {
+            push(
+                "l.block_number >= (SELECT int8send(MIN(number)) FROM blocks WHERE timestamp >= ?)"
+                    .into(),
+                Box::new(time),
+            );
+        }
+        if let Some(time) = self.to_time {
+            push(
+                "l.block_number <= (SELECT int8send(MAX(number)) FROM blocks WHERE timestamp <= ?)"
+                    .into(),
+                Box::new(time),
+            );
+        }
+        if let Some(address) = self.address {
+            let any = address_columns
+                .iter()
+                .map(|column| format!("e.{column} = ?"))
+                .collect::<Vec<_>>()
+                .join(" OR ");
+            push(format!("({any})"), Box::new(address.as_bytes().to_vec()));
+        }
+
+        for (column, min, max) in [
+            ("amount0", self.min_amount0, self.max_amount0),
+            ("amount1", self.min_amount1, self.max_amount1),
+        ] {
+            match amounts {
+                // Flipping the sign bit turns two's complement into an encoding whose byte
+                // order matches the numeric order.
+                Amounts::Signed => {
+                    let column = format!("set_byte(e.{column}, 0, get_byte(e.{column}, 0) # 128)");
+                    if let Some(min) = min {
+                        push(format!("{column} >= ?"), Box::new(offset_bytes(min)));
+                    }
+                    if let Some(max) = max {
+                        push(format!("{column} <= ?"), Box::new(offset_bytes(max)));
+                    }
+                }
+                Amounts::Unsigned => {
+                    if let Some(min) = min.filter(|min| !min.is_negative()) {
+                        push(
+                            format!("e.{column} >= ?"),
+                            Box::new(unsigned_bytes(min.into_raw())),
+                        );
+                    }
+                    if let Some(max) = max {
+                        // Nothing is below zero, so a negative maximum matches no row
+                        let (op, max) = match max.is_negative() {
+                            true => ("<", U256::zero()),
+                            false => ("<=", max.into_raw()),
+                        };
+                        push(format!("e.{column} {op} ?"), Box::new(unsigned_bytes(max)));
+                    }
+                }
+            }
+        }
+
+        match (ticks, self.tick_lower, self.tick_upper) {
+            (_, None, None) => {}
+            (Ticks::None, _, _) => {
+                return Err(eyre!("{} has no tick columns", kind.table()));
+            }
+            (Ticks::Single, lower, upper) => {
+                if let Some(lower) = lower {
+                    push("e.tick >= ?".into(), Box::new(lower));
+                }
+                if let Some(upper) = upper {
+                    push("e.tick <= ?".into(), Box::new(upper));
+                }
+            }
+            (Ticks::Range, lower, upper) => {
+                if let Some(lower) = lower {
+                    push("e.tick_lower >= ?".into(), Box::new(lower));
+                }
+                if let Some(upper) = upper {
+                    push("e.tick_upper <= ?".into(), Box::new(upper));
+                }
+            }
+        }
+
+        if let Some(Cursor(id)) = self.after {
+            push("e.id > ?".into(), Box::new(id));
+        }
+
+        let clause = match conditions.is_empty() {
+            true => String::new(),
+            false => format!("WHERE {}", conditions.join(" AND ")),
+        };
+
+        let sql = format!(
+            "
+           SELECT e.*, l.transaction_hash, l.block_number, l.address, l.log_index,
+                  l.transaction_index, b.hash AS block_hash
+           FROM {} e
+           LEFT JOIN ethereum_logs l ON l.id = e.ethereum_log_id
+           LEFT JOIN blocks b ON int8send(b.number) = l.block_number
+           {clause}
+           ORDER BY e.id
+           LIMIT {}
+           ",
+            kind.view(),
+            self.limit as u64 + 1
+        );
+
+        Ok((sql, params))
+    }
+}
+
+impl<T> Page<T> {
+    /// Builds a page from the rows selected by `EventQuery::sql`.
+    ///
+    /// # Arguments
+    ///
+    /// - `items`: Up to `limit + 1` events, ordered by id.
+    /// - `limit`: The maximum number of events per page.
+    pub fn new(mut items: Vec<Indexed<T>>, limit: u32) -> Self {
+        // The extra row only tells us that another page follows
+        let next = match items.len() > limit as usize {
+            true => {
+                items.truncate(limit as usize);
+                items.last().map(|item| Cursor(item.id))
+            }
+            false => None,
+        };
+
+        Self { items, next }
+    }
+}
+
+impl DB {
+    /// Reads a page of swap events matching `query`.
+    pub async fn swaps(&self, query: &EventQuery) -> Result<Page<SwapFilter>> {
+        self.query_events(EventKind::Swap, query).await
+    }
+
+    /// Reads a page of mint events matching `query`.
+    pub async fn mints(&self, query: &EventQuery) -> Result<Page<MintFilter>> {
+        self.query_events(EventKind::Mint, query).await
+    }
+
+    /// Reads a page of burn events matching `query`.
+    pub async fn burns(&self, query: &EventQuery) -> Result<Page<BurnFilter>> {
+        self.query_events(EventKind::Burn, query).await
+    }
+
+    /// Reads a page of flash events matching `query`.
+    ///
+    /// Flash events have no ticks, so a tick filter is an error.
+    pub async fn flashes(&self, query: &EventQuery) -> Result<Page<FlashFilter>> {
+        self.query_events(EventKind::Flash, query).await
+    }
+
+    /// Runs the query of a single event table.
+    async fn query_events<T: IndexedEvent>(
+        &self,
+        kind: EventKind,
+        query: &EventQuery,
+    ) -> Result<Page<T>> {
+        let (sql, params) = query.sql(kind)?;
+
+        let rows = self
+            .client
+            .query(&sql, &params.iter().map(|p| p.as_ref()).collect::<Vec<_>>())
+            .await?;
+
+        let items = rows
+            .iter()
+            .map(|row| {
+                Ok(Indexed {
+                    id: row.try_get("id")?,
+                    log: LogRecord::from_row(row)?,
+                    event: T::from_row(row)?,
+                })
+            })
+            .collect::<Result<_, IndexerError>>()?;
+
+        Ok(Page::new(items, query.limit))
+    }
+}
+
+/// Encodes a signed amount with its sign bit flipped, matching the query on signed columns.
+///
+/// The encodings of two amounts compare bytewise like the amounts themselves.
+pub fn offset_bytes(value: I256) -> Vec<u8> {
+    let mut bytes = unsigned_bytes(value.into_raw());
+    bytes[0] ^= 0x80;
+    bytes
+}
+
+fn unsigned_bytes(value: U256) -> Vec<u8> {
+    let mut bytes = vec![0u8; 32];
+    value.to_big_endian(&mut bytes);
+    bytes
+}
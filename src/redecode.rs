@@ -0,0 +1,181 @@
+use ethers::{
+    abi::RawLog,
+    prelude::LogMeta,
+    types::{H256, U256, U64},
+};
+use eyre::{eyre, Result};
+use log::{info, warn};
+use tokio_postgres::Row;
+
+use crate::database::DB;
+use crate::decode::decode_log;
+use crate::export::EventKind;
+use crate::storage::Storage;
+use crate::types::Address;
+
+/// The number of raw logs read per query.
+const BATCH_SIZE: i64 = 10_000;
+
+/// The event tables rebuilt from the raw logs, followed by the log table they reference.
+const DECODED_TABLES: [&str; 5] = [
+    "swap_logs",
+    "burn_logs",
+    "mint_logs",
+    "flash_logs",
+    "ethereum_logs",
+];
+
+/// Rebuilds the typed tables from the `raw_logs` table, without any RPC.
+///
+/// Only blocks with raw logs are rebuilt: their typed rows are deleted, and every raw log is
+/// decoded and inserted again, all in a single transaction. Logs that still fail to decode are
+/// skipped and counted.
+///
+/// # Arguments
+///
+/// - `db`: A reference to the PostgreSQL database instance.
+/// - `from`: The first block to rebuild, the first one by default.
+/// - `to`: The last block to rebuild, the last one by default.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the number of decoded logs.
+pub async fn redecode(db: &DB, from: Option<u64>, to: Option<u64>) -> Result<u64> {
+    let from = from.unwrap_or(0).to_be_bytes().to_vec();
+    let to = to.unwrap_or(u64::MAX).to_be_bytes().to_vec();
+
+    db.client.batch_execute("BEGIN").await?;
+
+    let result = async {
+        for kind in EventKind::ALL {
+            db.reverse_rollups(
+                kind,
+                "
+               e.block_number IN (
+                   SELECT block_number FROM raw_logs
+                   WHERE block_number BETWEEN $1 AND $2
+               )
+               ",
+                &[&from, &to],
+            )
+            .await?;
+        }
+
+        for table in DECODED_TABLES {
+            db.client
+                .execute(
+                    &format!(
+                        "
+                       DELETE FROM {table}
+                       WHERE block_number IN (
+                           SELECT block_number FROM raw_logs
+                           WHERE block_number BETWEEN $1 AND $2
+                       )
+                       "
+                    ),
+                    &[&from, &to],
+                )
+                .await?;
+        }
+
+        let (mut decoded, mut failed) = (0, 0);
+        let mut after = 0;
+
+        loop {
+            let rows = db
+                .client
+                .query(
+                    "
+                   SELECT * FROM raw_logs
+                   WHERE block_number BETWEEN $1 AND $2 AND id > $3
+                   ORDER BY id
+                   LIMIT $4
+                   ",
+                    &[&from, &to, &after, &BATCH_SIZE],
+                )
+                .await?;
+
+            for row in &rows {
+                let meta = row_meta(row)?;
+                let raw = row_raw_log(row)?.ok_or_else(|| eyre!("raw log without data"))?;
+
+                match decode_log(&raw) {
+                    Ok(event) => {
+                        db.insert_event(&meta, &event).await?;
+                        decoded += 1;
+                    }
+                    Err(e) => {
+                        warn!("Failed to decode log {:?}: {}", meta, e);
+                        failed += 1;
+                    }
+                }
+            }
+
+            match rows.last() {
+                Some(row) if rows.len() as i64 == BATCH_SIZE => after = row.get("id"),
+                _ => break,
+            }
+        }
+
+        info!("Decoded {} raw logs, {} failed", decoded, failed);
+
+        Ok::<_, eyre::Report>(decoded)
+    }
+    .await;
+
+    match result {
+        Ok(decoded) => {
+            db.commit_transaction().await?;
+            Ok(decoded)
+        }
+        Err(e) => {
+            db.rollback_transaction().await?;
+            Err(e)
+        }
+    }
+}
+
+/// Reads the log metadata of a `raw_logs` or `dead_letters` row.
+pub(crate) fn row_meta(row: &Row) -> Result<LogMeta> {
+    let block_number: Vec<u8> = row.get("block_number");
+    let block_number = u64::from_be_bytes(
+        block_number
+            .try_into()
+            .map_err(|_| eyre!("invalid block number"))?,
+    );
+
+    let hash = |column: &str| -> Result<H256> {
+        let raw: Vec<u8> = row.get(column);
+        match raw.len() {
+            32 => Ok(H256::from_slice(&raw)),
+            _ => Err(eyre!("invalid {}", column)),
+        }
+    };
+
+    Ok(LogMeta {
+        address: *row.get::<_, Address>("address"),
+        block_number: U64::from(block_number),
+        block_hash: hash("block_hash")?,
+        transaction_hash: hash("transaction_hash")?,
+        transaction_index: U64::from(row.get::<_, i64>("transaction_index") as u64),
+        log_index: U256::from(row.get::<_, i64>("log_index") as u64),
+    })
+}
+
+/// Reads the topics and data of a `raw_logs` or `dead_letters` row, if it has any.
+pub(crate) fn row_raw_log(row: &Row) -> Result<Option<RawLog>> {
+    let Some(data) = row.get::<_, Option<Vec<u8>>>("data") else {
+        return Ok(None);
+    };
+
+    let topics = ["topic0", "topic1", "topic2", "topic3"]
+        .into_iter()
+        .map_while(|column| row.get::<_, Option<Vec<u8>>>(column))
+        .map(|topic| match topic.len() {
+            32 => Ok(H256::from_slice(&topic)),
+            _ => Err(eyre!("invalid topic")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(RawLog { topics, data }))
+}
@@ -0,0 +1,266 @@
+use eyre::{eyre, Result};
+use log::{error, info};
+use std::time::{Duration, SystemTime};
+use tokio::task::JoinHandle;
+
+use crate::config::RetentionSettings;
+use crate::database::DB;
+
+/// The tables a retention policy can be set on.
+///
+/// `ethereum_logs` is not listed, since each of its rows is pruned together with its event. A
+/// rollup table is pruned together with the trader counts of its buckets.
+pub const PRUNABLE_TABLES: [&str; 9] = [
+    "swap_logs",
+    "burn_logs",
+    "mint_logs",
+    "flash_logs",
+    "raw_logs",
+    "dead_letters",
+    "rollups_minute",
+    "rollups_hour",
+    "rollups_day",
+];
+
+/// The event tables, whose `ethereum_logs` rows are pruned with them.
+const EVENT_TABLES: [&str; 4] = ["swap_logs", "burn_logs", "mint_logs", "flash_logs"];
+
+/// Checks that every table of the retention policy can be pruned.
+///
+/// # Arguments
+///
+/// - `settings`: The retention policy from the configuration file.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating whether the policy is valid.
+pub fn validate(settings: &RetentionSettings) -> Result<()> {
+    if settings.interval_secs == 0 {
+        return Err(eyre!("retention interval_secs must be greater than zero"));
+    }
+    if settings.batch_size <= 0 {
+        return Err(eyre!("retention batch_size must be greater than zero"));
+    }
+
+    for (table, days) in &settings.tables {
+        if !PRUNABLE_TABLES.contains(&table.as_str()) {
+            return Err(eyre!(
+                "retention is not supported on table {}, expected one of {}",
+                table,
+                PRUNABLE_TABLES.join(", ")
+            ));
+        }
+        if window(*days).is_none() {
+            return Err(eyre!("retention window of {} is too long", table));
+        }
+    }
+
+    Ok(())
+}
+
+/// Prunes every table of the retention policy once.
+///
+/// Rows from blocks mined before the retention window of their table, or rollup buckets ending
+/// before it, are deleted in batches of `batch_size` rows, each in its own statement, so a
+/// large backlog never holds locks for long. The `ethereum_logs` row of every deleted event is
+/// deleted with it.
+///
+/// # Arguments
+///
+/// - `db`: A reference to the PostgreSQL database instance.
+/// - `settings`: The retention policy from the configuration file.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the number of deleted rows.
+pub async fn prune(db: &DB, settings: &RetentionSettings) -> Result<u64> {
+    validate(settings)?;
+
+    let mut total = 0;
+
+    for (table, days) in &settings.tables {
+        // A window reaching before the epoch keeps everything
+        let Some(cutoff) = window(*days).and_then(|window| SystemTime::now().checked_sub(window))
+        else {
+            continue;
+        };
+
+        loop {
+            let deleted = db.prune_batch(table, cutoff, settings.batch_size).await?;
+            total += deleted;
+
+            if deleted < settings.batch_size as u64 {
+                break;
+            }
+        }
+    }
+
+    if total > 0 {
+        info!("Pruned {} rows past their retention window", total);
+    }
+
+    Ok(total)
+}
+
+/// The length of a retention window of `days` days, or `None` if it overflows.
+fn window(days: u64) -> Option<Duration> {
+    days.checked_mul(24 * 60 * 60).map(Duration::from_secs)
+}
+
+/// Spawns a background task pruning the database every `interval_secs` seconds.
+///
+/// The task uses its own database connection, so pruning never runs inside the block
+/// transactions of the indexer.
+///
+/// # Arguments
+///
+/// - `settings`: The retention policy from the configuration file.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the handle of the task, or an error if the policy is invalid.
+pub fn spawn(settings: RetentionSettings) -> Result<JoinHandle<()>> {
+    validate(&settings)?;
+
+    Ok(tokio::spawn(async move {
+        let db = match DB::new().await {
+            Ok(db) => db,
+            Err(e) => {
+                error!("Retention disabled, failed to connect: {}", e);
+                return;
+            }
+        };
+
+        let mut interval = tokio::time::interval(Duration::from_secs(settings.interval_secs));
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = prune(&db, &settings).await {
+                error!("Failed to prune tables: {}", e);
+            }
+        }
+    }))
+}
+
+/// Returns the statement deleting a batch of rows of `table` older than the cutoff `$1`,
+/// limited to `$2` rows and returning their number as `count`.
+///
+/// Block times only increase with the block number, so every row up to the last block mined
+/// before the cutoff is deleted. Nothing is deleted while no such block time is known.
+///
+/// # Arguments
+///
+/// - `table`: The table to prune, one of `PRUNABLE_TABLES`.
+///
+/// # Returns
+///
+/// Returns the statement, or `None` if the table cannot be pruned.
+pub fn prune_statement(table: &str) -> Option<String> {
+    if !PRUNABLE_TABLES.contains(&table) {
+        return None;
+    }
+
+    let boundary = "
+                   boundary AS (
+                       SELECT int8send(MAX(number)) AS block_number
+                       FROM blocks
+                       WHERE timestamp < $1
+                   )";
+
+    // Deleting the log rows explicitly also covers partitioned tables, which have no
+    // cascading foreign keys.
+    if EVENT_TABLES.contains(&table) {
+        return Some(format!(
+            "
+                   WITH {boundary},
+                   doomed AS (
+                       SELECT e.id
+                       FROM {table} e
+                       JOIN ethereum_logs l ON l.id = e.ethereum_log_id
+                       WHERE l.block_number <= (SELECT block_number FROM boundary)
+                       ORDER BY e.id
+                       LIMIT $2
+                   ),
+                   deleted AS (
+                       DELETE FROM {table} WHERE id IN (SELECT id FROM doomed)
+                       RETURNING ethereum_log_id
+                   ),
+                   logs AS (
+                       DELETE FROM ethereum_logs
+                       WHERE id IN (SELECT ethereum_log_id FROM deleted)
+                   )
+                   SELECT COUNT(*) AS count FROM deleted
+                   "
+        ));
+    }
+
+    // Buckets are only deleted once they end before the cutoff
+    if let Some(resolution) = table.strip_prefix("rollups_") {
+        return Some(format!(
+            "
+                   WITH doomed AS (
+                       SELECT pool, bucket
+                       FROM {table}
+                       WHERE bucket < date_trunc('{resolution}', $1::timestamptz)
+                       ORDER BY bucket
+                       LIMIT $2
+                   ),
+                   deleted AS (
+                       DELETE FROM {table}
+                       WHERE (pool, bucket) IN (SELECT pool, bucket FROM doomed)
+                       RETURNING pool, bucket
+                   ),
+                   traders AS (
+                       DELETE FROM rollup_traders_{resolution}
+                       WHERE (pool, bucket) IN (SELECT pool, bucket FROM deleted)
+                   )
+                   SELECT COUNT(*) AS count FROM deleted
+                   "
+        ));
+    }
+
+    Some(format!(
+        "
+                   WITH {boundary},
+                   doomed AS (
+                       SELECT id
+                       FROM {table}
+                       WHERE block_number <= (SELECT block_number FROM boundary)
+                       ORDER BY id
+                       LIMIT $2
+                   ),
+                   deleted AS (
+                       DELETE FROM {table} WHERE id IN (SELECT id FROM doomed)
+                       RETURNING id
+                   )
+                   SELECT COUNT(*) AS count FROM deleted
+                   "
+    ))
+}
+
+impl DB {
+    /// Deletes up to `limit` rows of `table` older than `cutoff`, with the log rows of deleted
+    /// events.
+    ///
+    /// # Arguments
+    ///
+    /// - `table`: The table, one of `PRUNABLE_TABLES`.
+    /// - `cutoff`: Rows from blocks or buckets older than this time are deleted.
+    /// - `limit`: The maximum number of rows to delete.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of deleted rows.
+    pub async fn prune_batch(&self, table: &str, cutoff: SystemTime, limit: i64) -> Result<u64> {
+        let Some(statement) = prune_statement(table) else {
+            return Err(eyre!("retention is not supported on table {}", table));
+        };
+
+        let row = self
+            .client
+            .query_one(&statement, &[&cutoff, &limit])
+            .await?;
+
+        Ok(row.get::<_, i64>("count") as u64)
+    }
+}
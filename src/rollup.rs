@@ -0,0 +1,310 @@
+use ethers::types::{H160, U256};
+use eyre::Result;
+use postgres_types::ToSql;
+use std::time::SystemTime;
+
+use crate::constants::POOL_FEE;
+use crate::database::DB;
+use crate::export::EventKind;
+use crate::types::pool_types::usdc_weth_pool::*;
+use crate::types::Address;
+
+/// The bucket sizes of the rollup tables, as `date_trunc` fields.
+pub const RESOLUTIONS: [&str; 3] = ["minute", "hour", "day"];
+
+/// The contribution of a single event to the rollup buckets it falls in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Contribution {
+    pub swaps: i64,
+    pub mints: i64,
+    pub burns: i64,
+    pub flashes: i64,
+
+    /// The absolute amounts of token0 and token1 swapped.
+    pub volume0: U256,
+    pub volume1: U256,
+
+    /// The fees paid in token0 and token1, by swaps on their input amount and by flashes.
+    pub fees0: U256,
+    pub fees1: U256,
+
+    /// The trader of a swap, counted once per bucket.
+    pub trader: Option<H160>,
+}
+
+/// Returns the contribution of an event to the rollups, or `None` for events that are not
+/// indexed.
+///
+/// The trader of a swap is its recipient, since the sender is usually a router.
+///
+/// # Arguments
+///
+/// - `event`: The decoded event.
+pub fn contribution(event: &USDC_WETH_POOLEvents) -> Option<Contribution> {
+    let fee = |amount: U256| amount * U256::from(POOL_FEE) / U256::from(1_000_000);
+
+    match event {
+        USDC_WETH_POOLEvents::SwapFilter(f) => Some(Contribution {
+            swaps: 1,
+            volume0: f.amount_0.unsigned_abs(),
+            volume1: f.amount_1.unsigned_abs(),
+            // The positive amount is the one paid into the pool
+            fees0: match f.amount_0.is_positive() {
+                true => fee(f.amount_0.into_raw()),
+                false => U256::zero(),
+            },
+            fees1: match f.amount_1.is_positive() {
+                true => fee(f.amount_1.into_raw()),
+                false => U256::zero(),
+            },
+            trader: Some(f.recipient),
+            ..Default::default()
+        }),
+        USDC_WETH_POOLEvents::MintFilter(_) => Some(Contribution {
+            mints: 1,
+            ..Default::default()
+        }),
+        USDC_WETH_POOLEvents::BurnFilter(_) => Some(Contribution {
+            burns: 1,
+            ..Default::default()
+        }),
+        USDC_WETH_POOLEvents::FlashFilter(f) => Some(Contribution {
+            flashes: 1,
+            fees0: f.paid_0,
+            fees1: f.paid_1,
+            ..Default::default()
+        }),
+        _ => None,
+    }
+}
+
+/// Returns the statements creating the rollup tables of every resolution.
+///
+/// Each `rollups_<resolution>` table holds one row per pool and bucket, and each
+/// `rollup_traders_<resolution>` table counts the swaps of every trader in a bucket, so that
+/// unique traders can be counted and uncounted incrementally.
+pub fn rollup_tables() -> String {
+    RESOLUTIONS
+        .iter()
+        .map(|resolution| {
+            format!(
+                "
+            CREATE TABLE IF NOT EXISTS rollups_{resolution} (
+                bucket TIMESTAMPTZ NOT NULL,
+                pool BYTEA NOT NULL,
+                swaps BIGINT NOT NULL DEFAULT 0,
+                mints BIGINT NOT NULL DEFAULT 0,
+                burns BIGINT NOT NULL DEFAULT 0,
+                flashes BIGINT NOT NULL DEFAULT 0,
+                volume0 NUMERIC NOT NULL DEFAULT 0,
+                volume1 NUMERIC NOT NULL DEFAULT 0,
+                fees0 NUMERIC NOT NULL DEFAULT 0,
+                fees1 NUMERIC NOT NULL DEFAULT 0,
+                traders BIGINT NOT NULL DEFAULT 0,
+                PRIMARY KEY (pool, bucket)
+            );
+
+            CREATE TABLE IF NOT EXISTS rollup_traders_{resolution} (
+                bucket TIMESTAMPTZ NOT NULL,
+                pool BYTEA NOT NULL,
+                trader BYTEA NOT NULL,
+                swaps BIGINT NOT NULL,
+                PRIMARY KEY (pool, bucket, trader)
+            );
+            "
+            )
+        })
+        .collect()
+}
+
+/// Formats an amount as a signed NUMERIC literal.
+fn numeric(amount: U256, sign: i64) -> String {
+    match sign < 0 && !amount.is_zero() {
+        true => format!("-{amount}"),
+        false => amount.to_string(),
+    }
+}
+
+impl DB {
+    /// Adds an event to, or removes it from, the rollup buckets of every resolution.
+    ///
+    /// Buckets follow the time of the block in the `blocks` table, so the block is written
+    /// first. Events of blocks whose time is unknown are left out of the rollups, both when
+    /// added and when removed.
+    ///
+    /// # Arguments
+    ///
+    /// - `pool`: The pool that emitted the event.
+    /// - `event`: The decoded event.
+    /// - `block_number`: The number of the block containing the event.
+    /// - `sign`: `1` to add the event, `-1` to remove it.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the update.
+    pub async fn apply_rollups(
+        &self,
+        pool: H160,
+        event: &USDC_WETH_POOLEvents,
+        block_number: u64,
+        sign: i64,
+    ) -> Result<()> {
+        let Some(c) = contribution(event) else {
+            return Ok(());
+        };
+
+        let at: Option<SystemTime> = self
+            .client
+            .query_opt(
+                "SELECT timestamp FROM blocks WHERE number = $1",
+                &[&(block_number as i64)],
+            )
+            .await?
+            .and_then(|row| row.get("timestamp"));
+        let Some(at) = at else {
+            return Ok(());
+        };
+        let pool: Address = pool.into();
+
+        for resolution in RESOLUTIONS {
+            let bucket = format!("date_trunc('{resolution}', $1::TIMESTAMPTZ)");
+
+            // A trader is unique in a bucket while it has at least one swap there
+            let mut traders = 0;
+            if let Some(trader) = c.trader {
+                let trader: Address = trader.into();
+                let row = self
+                    .client
+                    .query_one(
+                        &format!(
+                            "
+                           INSERT INTO rollup_traders_{resolution} AS t (bucket, pool, trader, swaps)
+                           VALUES ({bucket}, $2, $3, $4)
+                           ON CONFLICT (pool, bucket, trader)
+                           DO UPDATE SET swaps = t.swaps + EXCLUDED.swaps
+                           RETURNING swaps
+                           "
+                        ),
+                        &[&at, &pool, &trader, &sign],
+                    )
+                    .await?;
+
+                let swaps: i64 = row.get("swaps");
+                if sign > 0 && swaps == 1 {
+                    traders = 1;
+                } else if sign < 0 && swaps == 0 {
+                    traders = -1;
+                    self.client
+                        .execute(
+                            &format!(
+                                "
+                               DELETE FROM rollup_traders_{resolution}
+                               WHERE bucket = {bucket} AND pool = $2 AND trader = $3
+                               "
+                            ),
+                            &[&at, &pool, &trader],
+                        )
+                        .await?;
+                }
+            }
+
+            let params: [&(dyn ToSql + Sync); 11] = [
+                &at,
+                &pool,
+                &(c.swaps * sign),
+                &(c.mints * sign),
+                &(c.burns * sign),
+                &(c.flashes * sign),
+                &numeric(c.volume0, sign),
+                &numeric(c.volume1, sign),
+                &numeric(c.fees0, sign),
+                &numeric(c.fees1, sign),
+                &traders,
+            ];
+
+            self.client
+                .execute(
+                    &format!(
+                        "
+                       INSERT INTO rollups_{resolution} AS r (
+                           bucket, pool, swaps, mints, burns, flashes,
+                           volume0, volume1, fees0, fees1, traders
+                       )
+                       VALUES (
+                           {bucket}, $2, $3, $4, $5, $6,
+                           $7::TEXT::NUMERIC, $8::TEXT::NUMERIC, $9::TEXT::NUMERIC, $10::TEXT::NUMERIC,
+                           $11
+                       )
+                       ON CONFLICT (pool, bucket) DO UPDATE SET
+                           swaps = r.swaps + EXCLUDED.swaps,
+                           mints = r.mints + EXCLUDED.mints,
+                           burns = r.burns + EXCLUDED.burns,
+                           flashes = r.flashes + EXCLUDED.flashes,
+                           volume0 = r.volume0 + EXCLUDED.volume0,
+                           volume1 = r.volume1 + EXCLUDED.volume1,
+                           fees0 = r.fees0 + EXCLUDED.fees0,
+                           fees1 = r.fees1 + EXCLUDED.fees1,
+                           traders = r.traders + EXCLUDED.traders
+                       "
+                    ),
+                    &params,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the events matching a condition from the rollups, before they are deleted.
+    ///
+    /// Events without a log row are skipped, since their pool is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// - `kind`: The event table to read.
+    /// - `condition`: A SQL condition on the event rows, aliased `e`.
+    /// - `params`: The parameters of the condition.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of removed events.
+    pub async fn reverse_rollups(
+        &self,
+        kind: EventKind,
+        condition: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<u64> {
+        let rows = self
+            .client
+            .query(
+                &format!(
+                    "
+                   SELECT e.*, l.address, l.block_number AS log_block_number
+                   FROM {} e
+                   JOIN ethereum_logs l ON l.id = e.ethereum_log_id
+                   WHERE {condition}
+                   ",
+                    kind.view()
+                ),
+                params,
+            )
+            .await?;
+
+        let count = rows.len() as u64;
+        for row in rows {
+            let pool = *row.try_get::<_, Address>("address")?;
+            let block: Vec<u8> = row.try_get("log_block_number")?;
+            let block_number = u64::from_be_bytes(block.as_slice().try_into()?);
+            let event = match kind {
+                EventKind::Swap => USDC_WETH_POOLEvents::SwapFilter((&row).try_into()?),
+                EventKind::Mint => USDC_WETH_POOLEvents::MintFilter((&row).try_into()?),
+                EventKind::Burn => USDC_WETH_POOLEvents::BurnFilter((&row).try_into()?),
+                EventKind::Flash => USDC_WETH_POOLEvents::FlashFilter((&row).try_into()?),
+            };
+
+            self.apply_rollups(pool, &event, block_number, -1).await?;
+        }
+
+        Ok(count)
+    }
+}
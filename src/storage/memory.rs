@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use ethers::{abi::RawLog, prelude::LogMeta, types::H256};
+use eyre::{eyre, Result};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::coverage::{self, Interval};
+use crate::storage::Storage;
+use crate::types::pool_types::usdc_weth_pool::USDC_WETH_POOLEvents;
+
+/// An in-memory `Storage` implementation, mainly useful for tests.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    state: Mutex<MemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    /// Committed blocks, keyed by block number.
+    blocks: BTreeMap<u64, H256>,
+
+    /// Committed events in insertion order.
+    events: Vec<(LogMeta, USDC_WETH_POOLEvents)>,
+
+    /// The block opened by `insert_block`, if any.
+    open_block: Option<(u64, H256)>,
+
+    /// Events written into the open block.
+    pending: Vec<(LogMeta, USDC_WETH_POOLEvents)>,
+
+    /// Committed raw logs in insertion order.
+    raw_logs: Vec<(LogMeta, RawLog)>,
+
+    /// Raw logs written into the open block.
+    pending_raw_logs: Vec<(LogMeta, RawLog)>,
+
+    /// Logs that failed, with their error.
+    dead_letters: Vec<(LogMeta, Option<RawLog>, String)>,
+
+    /// Committed processed block ranges.
+    coverage: Vec<Interval>,
+
+    /// The processed block range recorded in the open block.
+    pending_coverage: Option<Interval>,
+}
+
+impl MemoryStorage {
+    /// Creates an empty `MemoryStorage`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns every committed event with its log metadata, in insertion order.
+    pub fn events(&self) -> Vec<(LogMeta, USDC_WETH_POOLEvents)> {
+        self.state.lock().unwrap().events.clone()
+    }
+
+    /// Returns every committed raw log with its log metadata, in insertion order.
+    pub fn raw_logs(&self) -> Vec<(LogMeta, RawLog)> {
+        self.state.lock().unwrap().raw_logs.clone()
+    }
+
+    /// Returns every dead letter with its error, in insertion order.
+    pub fn dead_letters(&self) -> Vec<(LogMeta, Option<RawLog>, String)> {
+        self.state.lock().unwrap().dead_letters.clone()
+    }
+
+    /// Returns the committed block numbers and hashes.
+    pub fn blocks(&self) -> Vec<(u64, H256)> {
+        let state = self.state.lock().unwrap();
+        state.blocks.iter().map(|(n, h)| (*n, *h)).collect()
+    }
+
+    /// Returns the committed processed block ranges.
+    pub fn coverage(&self) -> Vec<Interval> {
+        self.state.lock().unwrap().coverage.clone()
+    }
+}
+
+#[async_trait]
+impl Storage for MemoryStorage {
+    async fn create_table(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn insert_block(
+        &self,
+        block_number: u64,
+        block_hash: H256,
+        _timestamp: Option<u64>,
+    ) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.open_block.is_some() {
+            return Err(eyre!("block already open"));
+        }
+        state.open_block = Some((block_number, block_hash));
+        Ok(())
+    }
+
+    async fn insert_event(&self, meta: &LogMeta, event: &USDC_WETH_POOLEvents) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.open_block.is_none() {
+            return Err(eyre!("no open block"));
+        }
+        state.pending.push((meta.clone(), event.clone()));
+        Ok(())
+    }
+
+    async fn insert_raw_log(&self, meta: &LogMeta, log: &RawLog) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.open_block.is_none() {
+            return Err(eyre!("no open block"));
+        }
+        state.pending_raw_logs.push((meta.clone(), log.clone()));
+        Ok(())
+    }
+
+    async fn insert_dead_letter(
+        &self,
+        meta: &LogMeta,
+        log: Option<&RawLog>,
+        error: &str,
+    ) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state
+            .dead_letters
+            .push((meta.clone(), log.cloned(), error.to_string()));
+        Ok(())
+    }
+
+    async fn commit(&self) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        let (number, hash) = state
+            .open_block
+            .take()
+            .ok_or_else(|| eyre!("no open block"))?;
+        let pending = std::mem::take(&mut state.pending);
+        state.blocks.insert(number, hash);
+        state.events.extend(pending);
+        let pending_raw_logs = std::mem::take(&mut state.pending_raw_logs);
+        state.raw_logs.extend(pending_raw_logs);
+        if let Some(range) = state.pending_coverage.take() {
+            coverage::insert(&mut state.coverage, range);
+        }
+        Ok(())
+    }
+
+    async fn rollback_to_block(&self, block_number: u64) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.blocks.retain(|n, _| *n <= block_number);
+        state
+            .events
+            .retain(|(meta, _)| meta.block_number.as_u64() <= block_number);
+        state
+            .raw_logs
+            .retain(|(meta, _)| meta.block_number.as_u64() <= block_number);
+        state
+            .dead_letters
+            .retain(|(meta, _, _)| meta.block_number.as_u64() <= block_number);
+        coverage::truncate(&mut state.coverage, block_number);
+        Ok(())
+    }
+
+    async fn cover_blocks(&self, from: u64, to: u64) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        let range = Interval {
+            start: from,
+            end: to,
+        };
+        match state.open_block {
+            Some(_) => state.pending_coverage = Some(range),
+            None => coverage::insert(&mut state.coverage, range),
+        }
+        Ok(())
+    }
+
+    async fn checkpoint(&self) -> Result<Option<u64>> {
+        let state = self.state.lock().unwrap();
+        Ok(state.blocks.keys().next_back().copied())
+    }
+
+    async fn block_hash(&self, block_number: u64) -> Result<Option<H256>> {
+        let state = self.state.lock().unwrap();
+        Ok(state.blocks.get(&block_number).copied())
+    }
+}
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use ethers::{abi::RawLog, prelude::LogMeta, types::H256};
+use eyre::{eyre, Result};
+
+pub mod memory;
+pub mod parquet;
+pub mod sqlite;
+pub mod tee;
+
+use crate::types::pool_types::usdc_weth_pool::USDC_WETH_POOLEvents;
+
+/// A destination for decoded pool events.
+///
+/// Events are written one block at a time. A block is opened with `insert_block`, its events are
+/// written with `insert_event`, and it becomes visible to readers once `commit` is called. The
+/// highest committed block is the checkpoint the indexer resumes from.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    /// Creates the tables used by the storage backend if they do not already exist.
+    async fn create_table(&self) -> Result<()>;
+
+    /// Opens a new block, which holds every event written until the next `commit`.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_number`: The number of the block.
+    /// - `block_hash`: The hash of the block.
+    /// - `timestamp`: The timestamp of the block in seconds since the epoch, if known.
+    async fn insert_block(
+        &self,
+        block_number: u64,
+        block_hash: H256,
+        timestamp: Option<u64>,
+    ) -> Result<()>;
+
+    /// Writes a decoded event and its log metadata into the open block.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: LogMeta of the log the event was decoded from.
+    /// - `event`: The decoded pool event.
+    async fn insert_event(&self, meta: &LogMeta, event: &USDC_WETH_POOLEvents) -> Result<()>;
+
+    /// Writes the undecoded topics and data of a log into the open block. Backends that do not
+    /// keep raw logs ignore it.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: LogMeta of the log.
+    /// - `log`: The topics and data of the log.
+    async fn insert_raw_log(&self, _meta: &LogMeta, _log: &RawLog) -> Result<()> {
+        Ok(())
+    }
+
+    /// Keeps a log that failed to decode or to be written, so indexing can continue without it.
+    /// Backends without dead letters return the error instead, which stops the indexer.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: LogMeta of the failed log.
+    /// - `log`: The topics and data of the log, if known.
+    /// - `error`: The error the log failed with.
+    async fn insert_dead_letter(
+        &self,
+        _meta: &LogMeta,
+        _log: Option<&RawLog>,
+        error: &str,
+    ) -> Result<()> {
+        Err(eyre!("{}", error))
+    }
+
+    /// Commits the open block and every event written into it.
+    async fn commit(&self) -> Result<()>;
+
+    /// Removes every committed block above `block_number` along with its events.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_number`: The last block to keep.
+    async fn rollback_to_block(&self, block_number: u64) -> Result<()>;
+
+    /// Records that every block from `from` to `to` has been processed, as part of the open
+    /// block, or at once when no block is open, such as after a batch of committed blocks.
+    /// Backends that do not track coverage ignore it.
+    ///
+    /// # Arguments
+    ///
+    /// - `from`: The first processed block.
+    /// - `to`: The last processed block, the open one if any.
+    async fn cover_blocks(&self, _from: u64, _to: u64) -> Result<()> {
+        Ok(())
+    }
+
+    /// Reads the number of the highest committed block, if any.
+    async fn checkpoint(&self) -> Result<Option<u64>>;
+
+    /// Reads the hash of a committed block. Backends that do not keep block hashes return
+    /// `None`, and every replayed block is then treated as a reorg.
+    ///
+    /// # Arguments
+    ///
+    /// - `block_number`: The number of the block.
+    async fn block_hash(&self, _block_number: u64) -> Result<Option<H256>> {
+        Ok(None)
+    }
+
+    /// Flushes anything the backend buffers, once the indexer stops.
+    async fn close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: Storage + ?Sized> Storage for Box<S> {
+    async fn create_table(&self) -> Result<()> {
+        (**self).create_table().await
+    }
+
+    async fn insert_block(
+        &self,
+        block_number: u64,
+        block_hash: H256,
+        timestamp: Option<u64>,
+    ) -> Result<()> {
+        (**self)
+            .insert_block(block_number, block_hash, timestamp)
+            .await
+    }
+
+    async fn insert_event(&self, meta: &LogMeta, event: &USDC_WETH_POOLEvents) -> Result<()> {
+        (**self).insert_event(meta, event).await
+    }
+
+    async fn insert_raw_log(&self, meta: &LogMeta, log: &RawLog) -> Result<()> {
+        (**self).insert_raw_log(meta, log).await
+    }
+
+    async fn insert_dead_letter(
+        &self,
+        meta: &LogMeta,
+        log: Option<&RawLog>,
+        error: &str,
+    ) -> Result<()> {
+        (**self).insert_dead_letter(meta, log, error).await
+    }
+
+    async fn commit(&self) -> Result<()> {
+        (**self).commit().await
+    }
+
+    async fn rollback_to_block(&self, block_number: u64) -> Result<()> {
+        (**self).rollback_to_block(block_number).await
+    }
+
+    async fn cover_blocks(&self, from: u64, to: u64) -> Result<()> {
+        (**self).cover_blocks(from, to).await
+    }
+
+    async fn checkpoint(&self) -> Result<Option<u64>> {
+        (**self).checkpoint().await
+    }
+
+    async fn block_hash(&self, block_number: u64) -> Result<Option<H256>> {
+        (**self).block_hash(block_number).await
+    }
+
+    async fn close(&self) -> Result<()> {
+        (**self).close().await
+    }
+}
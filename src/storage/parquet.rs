@@ -0,0 +1,206 @@
+use async_trait::async_trait;
+use ethers::{prelude::LogMeta, types::H256};
+use eyre::{eyre, Result};
+use log::info;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::export::{parquet::write_tables, ExportFilter, Tables};
+use crate::storage::Storage;
+use crate::types::pool_types::usdc_weth_pool::USDC_WETH_POOLEvents;
+
+/// A `Storage` sink that writes events to Parquet files rolled by block range.
+///
+/// Committed blocks are buffered in memory by range of `blocks_per_file` blocks. A range is
+/// written to one file per table, named after the first and last block it holds, e.g.
+/// `swaps_0018000000_0018009999.parquet`, once a block of a later range arrives and its last
+/// block is `reorg_depth` blocks behind the newest one. Flushed files are never rewritten, so
+/// the blocks a reorg may still roll back are kept in memory, and rolling back into a flushed
+/// range is an error.
+#[derive(Debug)]
+pub struct ParquetSink {
+    /// The directory the files are written to.
+    dir: PathBuf,
+
+    /// The number of blocks covered by each file.
+    blocks_per_file: u64,
+
+    /// The number of blocks kept in memory behind the newest one.
+    reorg_depth: u64,
+
+    state: Mutex<SinkState>,
+}
+
+#[derive(Debug, Default)]
+struct SinkState {
+    /// The block opened by `insert_block`, if any.
+    open_block: Option<u64>,
+
+    /// Events written into the open block.
+    pending: Tables,
+
+    /// The ranges not yet written to a file, keyed by their first block.
+    ranges: BTreeMap<u64, Range>,
+
+    /// The last block written to a file.
+    flushed_to: Option<u64>,
+}
+
+/// The committed events of a range of blocks.
+#[derive(Debug, Default)]
+struct Range {
+    /// Committed events of the range.
+    tables: Tables,
+
+    /// The first and last committed block of the range.
+    first: u64,
+    last: u64,
+}
+
+impl ParquetSink {
+    /// Opens a sink writing to `dir`, created if missing.
+    ///
+    /// The checkpoint is recovered from the names of the files already in `dir`.
+    ///
+    /// # Arguments
+    ///
+    /// - `dir`: The directory the files are written to.
+    /// - `blocks_per_file`: The number of blocks covered by each file.
+    /// - `reorg_depth`: The number of blocks kept in memory behind the newest one.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the sink, or an error if `dir` cannot be read.
+    pub fn open(dir: impl AsRef<Path>, blocks_per_file: u64, reorg_depth: u64) -> Result<Self> {
+        if blocks_per_file == 0 {
+            return Err(eyre!("blocks_per_file must be greater than zero"));
+        }
+
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        // Files are named `<table>_<first>_<last>.parquet`
+        let mut flushed_to = None;
+        for entry in fs::read_dir(&dir)? {
+            let name = entry?.file_name();
+            let last = name
+                .to_str()
+                .and_then(|n| n.strip_prefix("logs_"))
+                .and_then(|n| n.strip_suffix(".parquet"))
+                .and_then(|n| n.split('_').nth(1))
+                .and_then(|n| n.parse::<u64>().ok());
+            flushed_to = flushed_to.max(last);
+        }
+
+        Ok(Self {
+            dir,
+            blocks_per_file,
+            reorg_depth,
+            state: Mutex::new(SinkState {
+                flushed_to,
+                ..Default::default()
+            }),
+        })
+    }
+
+    /// Writes the buffered ranges before `before` to disk, or every range when `None`.
+    fn flush(&self, state: &mut SinkState, before: Option<u64>) -> Result<()> {
+        while let Some(entry) = state.ranges.first_entry() {
+            if before.is_some_and(|b| *entry.key() >= b) {
+                break;
+            }
+
+            let range = entry.remove();
+            let suffix = format!("_{:010}_{:010}", range.first, range.last);
+            write_tables(&range.tables, &ExportFilter::default(), &self.dir, &suffix)?;
+            state.flushed_to = Some(range.last);
+
+            info!(
+                "Wrote Parquet files for blocks {} to {}",
+                range.first, range.last
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Storage for ParquetSink {
+    async fn create_table(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn insert_block(
+        &self,
+        block_number: u64,
+        _block_hash: H256,
+        _timestamp: Option<u64>,
+    ) -> Result<()> {
+        self.state.lock().unwrap().open_block = Some(block_number);
+        Ok(())
+    }
+
+    async fn insert_event(&self, meta: &LogMeta, event: &USDC_WETH_POOLEvents) -> Result<()> {
+        self.state.lock().unwrap().pending.push(meta, event);
+        Ok(())
+    }
+
+    async fn commit(&self) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        let block = state
+            .open_block
+            .take()
+            .ok_or_else(|| eyre!("no open block"))?;
+
+        let SinkState {
+            pending, ranges, ..
+        } = &mut *state;
+        let range = ranges
+            .entry(block - block % self.blocks_per_file)
+            .or_insert_with(|| Range {
+                first: block,
+                ..Default::default()
+            });
+        range.tables.append(pending);
+        range.last = block;
+
+        // Ranges out of reach of a reorg are complete, and can be written
+        let settled = block.saturating_sub(self.reorg_depth);
+        let before = settled - settled % self.blocks_per_file;
+        self.flush(&mut state, Some(before))
+    }
+
+    async fn rollback_to_block(&self, block_number: u64) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.flushed_to.is_some_and(|last| last > block_number) {
+            return Err(eyre!(
+                "cannot roll back to block {} in flushed Parquet files",
+                block_number
+            ));
+        }
+
+        state.ranges.retain(|_, range| range.first <= block_number);
+        if let Some(mut entry) = state.ranges.last_entry() {
+            let range = entry.get_mut();
+            range.tables.truncate_to_block(block_number);
+            range.last = range.last.min(block_number);
+        }
+
+        Ok(())
+    }
+
+    async fn checkpoint(&self) -> Result<Option<u64>> {
+        let state = self.state.lock().unwrap();
+        let buffered = state.ranges.values().next_back().map(|range| range.last);
+        Ok(buffered.or(state.flushed_to))
+    }
+
+    async fn close(&self) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        self.flush(&mut state, None)
+    }
+}
@@ -0,0 +1,441 @@
+use async_trait::async_trait;
+use ethers::{abi::RawLog, prelude::LogMeta, types::H256};
+use eyre::Result;
+use log::info;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::export::fixed;
+use crate::indexed::{Column, IndexedEvent, Schema, SqlValue, Value, SCHEMAS};
+use crate::storage::Storage;
+use crate::types::pool_types::usdc_weth_pool::*;
+use crate::types::Address;
+
+/// A `Storage` implementation backed by an embedded SQLite database.
+///
+/// The schema mirrors the PostgreSQL one created by `DB::create_table`, with every big integer
+/// and address stored as a big-endian BLOB.
+#[derive(Debug)]
+pub struct SqliteStorage {
+    /// The SQLite connection.
+    pub conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteStorage {
+    /// Opens, or creates, the SQLite database at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: Path of the database file.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the storage, or an error if the file cannot be opened.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open(path.as_ref())?;
+        info!("Opened SQLite database at {}", path.as_ref().display());
+        Self::with_connection(conn)
+    }
+
+    /// Opens a new in-memory SQLite database.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        // Foreign keys are off by default, and the cascading deletes depend on them.
+        conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Runs a closure on the connection in the blocking thread pool, so the SQLite calls do
+    /// not stall the async runtime.
+    ///
+    /// # Arguments
+    ///
+    /// - `f`: The closure, given the locked connection.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the value returned by the closure.
+    pub(crate) async fn blocking<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> Result<T> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        tokio::task::spawn_blocking(move || f(&conn.lock().unwrap())).await?
+    }
+
+    /// Runs a write inside a savepoint of the open transaction, rolled back on error so a
+    /// failed event leaves no partial rows behind.
+    ///
+    /// # Arguments
+    ///
+    /// - `conn`: The connection with an open transaction.
+    /// - `write`: The statements to run.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the output of the write.
+    fn savepoint<T>(conn: &Connection, write: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+        conn.execute_batch("SAVEPOINT write")?;
+
+        match write(conn) {
+            Ok(value) => {
+                conn.execute_batch("RELEASE SAVEPOINT write")?;
+                Ok(value)
+            }
+            Err(e) => {
+                conn.execute_batch("ROLLBACK TO SAVEPOINT write; RELEASE SAVEPOINT write")?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Inserts Ethereum transaction logs into the `ethereum_logs` table.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: LogMeta containing Ethereum transaction log data.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the id of the inserted row.
+    fn insert_transaction_logs(conn: &Connection, meta: &LogMeta) -> Result<i64> {
+        let address: Address = meta.address.into();
+
+        conn.execute(
+            "
+            INSERT INTO ethereum_logs (transaction_hash, block_number, address)
+            VALUES (?1, ?2, ?3)
+            ",
+            params![
+                meta.transaction_hash.as_bytes(),
+                &meta.block_number.as_u64().to_be_bytes()[..],
+                address,
+            ],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Inserts an event into its table, named and laid out after `T::SCHEMA`.
+    ///
+    /// # Arguments
+    ///
+    /// - `conn`: The connection with an open transaction.
+    /// - `ethereum_log_id`: The id of the `ethereum_logs` row the event belongs to.
+    /// - `event`: The event to insert.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the insert.
+    fn insert_indexed<T: IndexedEvent + Debug>(
+        conn: &Connection,
+        ethereum_log_id: i64,
+        event: &T,
+    ) -> Result<()> {
+        let schema = T::SCHEMA;
+        let names: Vec<String> = schema.columns.iter().map(Column::sqlite_name).collect();
+        let placeholders: Vec<String> = (2..names.len() + 2).map(|i| format!("?{i}")).collect();
+
+        // Addresses are stored as their bytes, without a dictionary
+        let values: Vec<Box<dyn SqlValue>> = event
+            .values()
+            .into_iter()
+            .map(|value| match value {
+                Value::Address(address) => {
+                    let address: Address = address.into();
+                    Box::new(address) as _
+                }
+                Value::Sql(value) => value,
+            })
+            .collect();
+        let mut params: Vec<&dyn rusqlite::ToSql> = vec![&ethereum_log_id];
+        params.extend(values.iter().map(|v| v.sqlite()));
+
+        conn.execute(
+            &format!(
+                "
+                INSERT INTO {} (ethereum_log_id, {})
+                VALUES (?1, {})
+                ",
+                schema.table,
+                names.join(", "),
+                placeholders.join(", ")
+            ),
+            params.as_slice(),
+        )?;
+        info!("Inserting into {}: {:?}", schema.table, event);
+
+        Ok(())
+    }
+}
+
+/// Returns the statement creating the table of an event type, if it does not already exist.
+///
+/// The columns follow the schema of the PostgreSQL table, without its block number, and with
+/// addresses stored as `<name>_address` BLOBs.
+///
+/// # Arguments
+///
+/// - `schema`: The schema of the event type.
+fn event_table(schema: &Schema) -> String {
+    let columns: String = schema
+        .columns
+        .iter()
+        .map(|c| {
+            format!(
+                "\n            {} {},",
+                c.sqlite_name(),
+                c.kind.sqlite_type()
+            )
+        })
+        .collect();
+
+    format!(
+        "
+        CREATE TABLE IF NOT EXISTS {} (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            ethereum_log_id INTEGER REFERENCES ethereum_logs(id) ON DELETE CASCADE,{columns}
+            timestamp TEXT DEFAULT CURRENT_TIMESTAMP NOT NULL
+        );
+        ",
+        schema.table
+    )
+}
+
+#[async_trait]
+impl Storage for SqliteStorage {
+    async fn create_table(&self) -> Result<()> {
+        self.blocking(move |conn| {
+            conn.execute_batch(
+                "
+                CREATE TABLE IF NOT EXISTS blocks (
+                    number INTEGER PRIMARY KEY,
+                    hash BLOB NOT NULL,
+                    timestamp INTEGER
+                );
+
+                CREATE TABLE IF NOT EXISTS ethereum_logs (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    transaction_hash BLOB NOT NULL,
+                    block_number BLOB NOT NULL,
+                    address BLOB NOT NULL,
+                    timestamp TEXT DEFAULT CURRENT_TIMESTAMP NOT NULL
+                );
+
+                CREATE TABLE IF NOT EXISTS dead_letters (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    block_number BLOB NOT NULL,
+                    block_hash BLOB NOT NULL,
+                    transaction_hash BLOB NOT NULL,
+                    transaction_index INTEGER NOT NULL,
+                    log_index INTEGER NOT NULL,
+                    address BLOB NOT NULL,
+                    topic0 BLOB,
+                    topic1 BLOB,
+                    topic2 BLOB,
+                    topic3 BLOB,
+                    data BLOB,
+                    error TEXT NOT NULL,
+                    retries INTEGER DEFAULT 0 NOT NULL,
+                    timestamp TEXT DEFAULT CURRENT_TIMESTAMP NOT NULL
+                );
+                ",
+            )?;
+
+            for schema in SCHEMAS {
+                conn.execute_batch(&event_table(&schema))?;
+            }
+
+            // Tables created before blocks carried their timestamp
+            let has_timestamp: bool = conn.query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('blocks') WHERE name = 'timestamp'",
+                [],
+                |row| row.get(0),
+            )?;
+            if !has_timestamp {
+                conn.execute_batch("ALTER TABLE blocks ADD COLUMN timestamp INTEGER")?;
+            }
+
+            // Log a message indicating successful table creation.
+            info!("Tables created successfully");
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn insert_block(
+        &self,
+        block_number: u64,
+        block_hash: H256,
+        timestamp: Option<u64>,
+    ) -> Result<()> {
+        self.blocking(move |conn| {
+            // Everything up to the next commit is written in a single transaction.
+            conn.execute_batch("BEGIN")?;
+            conn.execute(
+                "INSERT OR REPLACE INTO blocks (number, hash, timestamp) VALUES (?1, ?2, ?3)",
+                params![
+                    block_number as i64,
+                    block_hash.as_bytes(),
+                    timestamp.map(|t| t as i64)
+                ],
+            )?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn insert_event(&self, meta: &LogMeta, event: &USDC_WETH_POOLEvents) -> Result<()> {
+        let meta = meta.clone();
+        let event = event.clone();
+
+        self.blocking(move |conn| {
+            Self::savepoint(conn, |conn| {
+                let id = Self::insert_transaction_logs(conn, &meta)?;
+
+                match &event {
+                    USDC_WETH_POOLEvents::SwapFilter(f) => Self::insert_indexed(conn, id, f)?,
+                    USDC_WETH_POOLEvents::BurnFilter(f) => Self::insert_indexed(conn, id, f)?,
+                    USDC_WETH_POOLEvents::MintFilter(f) => Self::insert_indexed(conn, id, f)?,
+                    USDC_WETH_POOLEvents::FlashFilter(f) => Self::insert_indexed(conn, id, f)?,
+                    _ => {}
+                }
+
+                Ok(())
+            })
+        })
+        .await
+    }
+
+    async fn insert_dead_letter(
+        &self,
+        meta: &LogMeta,
+        log: Option<&RawLog>,
+        error: &str,
+    ) -> Result<()> {
+        let meta = meta.clone();
+        let log = log.cloned();
+        let error = error.to_string();
+
+        self.blocking(move |conn| {
+            let address: Address = meta.address.into();
+            let topic = |i: usize| {
+                log.as_ref()
+                    .and_then(|l| l.topics.get(i))
+                    .map(|t| t.as_bytes())
+            };
+
+            conn.execute(
+                "
+                INSERT INTO dead_letters (
+                    block_number,
+                    block_hash,
+                    transaction_hash,
+                    transaction_index,
+                    log_index,
+                    address,
+                    topic0,
+                    topic1,
+                    topic2,
+                    topic3,
+                    data,
+                    error
+                )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+                ",
+                params![
+                    &meta.block_number.as_u64().to_be_bytes()[..],
+                    meta.block_hash.as_bytes(),
+                    meta.transaction_hash.as_bytes(),
+                    meta.transaction_index.as_u64() as i64,
+                    meta.log_index.as_u64() as i64,
+                    address,
+                    topic(0),
+                    topic(1),
+                    topic(2),
+                    topic(3),
+                    log.as_ref().map(|l| l.data.as_slice()),
+                    error,
+                ],
+            )?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn commit(&self) -> Result<()> {
+        self.blocking(move |conn| {
+            conn.execute_batch("COMMIT")?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn rollback_to_block(&self, block_number: u64) -> Result<()> {
+        self.blocking(move |conn| {
+            // Rolled back on error when dropped
+            let tx = conn.unchecked_transaction()?;
+
+            // Event rows are removed through the ON DELETE CASCADE foreign keys.
+            tx.execute(
+                "DELETE FROM ethereum_logs WHERE block_number > ?1",
+                params![&block_number.to_be_bytes()[..]],
+            )?;
+            tx.execute(
+                "DELETE FROM dead_letters WHERE block_number > ?1",
+                params![&block_number.to_be_bytes()[..]],
+            )?;
+            tx.execute(
+                "DELETE FROM blocks WHERE number > ?1",
+                params![block_number as i64],
+            )?;
+            tx.commit()?;
+
+            info!("Rolled back to block {}", block_number);
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn checkpoint(&self) -> Result<Option<u64>> {
+        self.blocking(move |conn| {
+            let number: Option<i64> = conn
+                .query_row("SELECT MAX(number) FROM blocks", [], |row| row.get(0))
+                .optional()?
+                .flatten();
+
+            Ok(number.map(|n| n as u64))
+        })
+        .await
+    }
+
+    async fn block_hash(&self, block_number: u64) -> Result<Option<H256>> {
+        self.blocking(move |conn| {
+            let hash: Option<Vec<u8>> = conn
+                .query_row(
+                    "SELECT hash FROM blocks WHERE number = ?1",
+                    params![block_number as i64],
+                    |row| row.get(0),
+                )
+                .optional()?;
+
+            Ok(hash
+                .map(|h| fixed::<32>("block hash", h))
+                .transpose()?
+                .map(H256))
+        })
+        .await
+    }
+}
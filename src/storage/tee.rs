@@ -0,0 +1,165 @@
+use async_trait::async_trait;
+use ethers::{abi::RawLog, prelude::LogMeta, types::H256};
+use eyre::Result;
+use std::sync::Mutex;
+
+use crate::storage::Storage;
+use crate::types::pool_types::usdc_weth_pool::USDC_WETH_POOLEvents;
+
+/// A `Storage` that writes every block to two backends.
+///
+/// The primary backend is the source of truth, the secondary one is a sink that follows it,
+/// such as the Parquet sink. A secondary that fell behind the primary, e.g. after losing the
+/// blocks it buffered in a crash, resumes from its own checkpoint: the blocks the primary
+/// already holds with the same hash are replayed to the secondary only, and a block whose hash
+/// changed in the meantime rolls the primary back first.
+#[derive(Debug)]
+pub struct Tee<A, B> {
+    /// The backend the blocks are checked against.
+    pub primary: A,
+
+    /// The backend that mirrors the primary one.
+    pub secondary: B,
+
+    state: Mutex<TeeState>,
+}
+
+#[derive(Debug, Default)]
+struct TeeState {
+    /// The checkpoint of the primary while the secondary catches up to it.
+    replay_to: Option<u64>,
+
+    /// Whether the open block is only written to the secondary.
+    replaying: bool,
+}
+
+impl<A, B> Tee<A, B> {
+    /// Creates a new `Tee` writing to `primary` and `secondary`.
+    pub fn new(primary: A, secondary: B) -> Self {
+        Self {
+            primary,
+            secondary,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Whether the open block is only written to the secondary.
+    fn replaying(&self) -> bool {
+        self.state.lock().unwrap().replaying
+    }
+}
+
+#[async_trait]
+impl<A: Storage, B: Storage> Storage for Tee<A, B> {
+    async fn create_table(&self) -> Result<()> {
+        self.primary.create_table().await?;
+        self.secondary.create_table().await
+    }
+
+    async fn insert_block(
+        &self,
+        block_number: u64,
+        block_hash: H256,
+        timestamp: Option<u64>,
+    ) -> Result<()> {
+        let replay_to = self.state.lock().unwrap().replay_to;
+
+        let replaying = match replay_to {
+            Some(last) if block_number <= last => {
+                let replaying = self.primary.block_hash(block_number).await? == Some(block_hash);
+                if !replaying {
+                    // The chain changed while the secondary was behind
+                    self.primary
+                        .rollback_to_block(block_number.saturating_sub(1))
+                        .await?;
+                }
+                replaying
+            }
+            _ => false,
+        };
+        *self.state.lock().unwrap() = TeeState {
+            replay_to: replay_to.filter(|_| replaying),
+            replaying,
+        };
+
+        if !replaying {
+            self.primary
+                .insert_block(block_number, block_hash, timestamp)
+                .await?;
+        }
+        self.secondary
+            .insert_block(block_number, block_hash, timestamp)
+            .await
+    }
+
+    async fn insert_event(&self, meta: &LogMeta, event: &USDC_WETH_POOLEvents) -> Result<()> {
+        if !self.replaying() {
+            self.primary.insert_event(meta, event).await?;
+        }
+        self.secondary.insert_event(meta, event).await
+    }
+
+    async fn insert_raw_log(&self, meta: &LogMeta, log: &RawLog) -> Result<()> {
+        if !self.replaying() {
+            self.primary.insert_raw_log(meta, log).await?;
+        }
+        self.secondary.insert_raw_log(meta, log).await
+    }
+
+    /// Dead letters are only kept by the primary backend, which already holds those of the
+    /// replayed blocks.
+    async fn insert_dead_letter(
+        &self,
+        meta: &LogMeta,
+        log: Option<&RawLog>,
+        error: &str,
+    ) -> Result<()> {
+        match self.replaying() {
+            true => Ok(()),
+            false => self.primary.insert_dead_letter(meta, log, error).await,
+        }
+    }
+
+    async fn commit(&self) -> Result<()> {
+        if !self.replaying() {
+            self.primary.commit().await?;
+        }
+        self.secondary.commit().await
+    }
+
+    async fn rollback_to_block(&self, block_number: u64) -> Result<()> {
+        self.primary.rollback_to_block(block_number).await?;
+        self.secondary.rollback_to_block(block_number).await?;
+
+        let mut state = self.state.lock().unwrap();
+        state.replay_to = state.replay_to.map(|last| last.min(block_number));
+        Ok(())
+    }
+
+    async fn cover_blocks(&self, from: u64, to: u64) -> Result<()> {
+        self.primary.cover_blocks(from, to).await?;
+        self.secondary.cover_blocks(from, to).await
+    }
+
+    async fn checkpoint(&self) -> Result<Option<u64>> {
+        let primary = self.primary.checkpoint().await?;
+
+        // A secondary without a checkpoint starts along with the primary
+        match (primary, self.secondary.checkpoint().await?) {
+            (Some(last), Some(secondary)) if secondary < last => {
+                self.state.lock().unwrap().replay_to = Some(last);
+                Ok(Some(secondary))
+            }
+            _ => Ok(primary),
+        }
+    }
+
+    async fn block_hash(&self, block_number: u64) -> Result<Option<H256>> {
+        self.primary.block_hash(block_number).await
+    }
+
+    async fn close(&self) -> Result<()> {
+        self.primary.close().await?;
+        self.secondary.close().await
+    }
+}
@@ -0,0 +1,207 @@
+use ethers::{
+    types::{Sign, H160, I256, U256},
+    utils::to_checksum,
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+use crate::types::{Address, Wu128, WI256, WU256};
+
+/// The text encoding of big integers in serialized values.
+///
+/// Values are always deserialized from either encoding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    /// A `0x` prefixed hex string, with a leading `-` for negative values.
+    Hex,
+
+    /// A decimal string.
+    #[default]
+    Decimal,
+}
+
+impl Encoding {
+    /// Encodes a big integer.
+    pub fn encode<T: BigInt>(&self, value: &T) -> String {
+        match self {
+            Self::Hex => value.to_hex(),
+            Self::Decimal => value.to_decimal(),
+        }
+    }
+}
+
+/// A big integer that can be written as text in both encodings.
+pub trait BigInt: Sized {
+    /// Writes the value as a decimal string.
+    fn to_decimal(&self) -> String;
+
+    /// Writes the value as a `0x` prefixed hex string.
+    fn to_hex(&self) -> String;
+
+    /// Reads the value from a decimal or `0x` prefixed hex string.
+    fn parse(text: &str) -> Result<Self, String>;
+}
+
+/// Splits a `0x` prefix from a string, returning `None` for a decimal string.
+fn hex_digits(text: &str) -> Option<&str> {
+    text.strip_prefix("0x").or_else(|| text.strip_prefix("0X"))
+}
+
+/// Reads an unsigned 256-bit integer, without the panic of `U256::from_str_radix` on long
+/// strings.
+fn parse_u256(text: &str) -> Result<U256, String> {
+    match hex_digits(text) {
+        Some(digits) if digits.is_empty() || digits.len() > 64 => {
+            Err(format!("invalid hex integer {text}"))
+        }
+        Some(digits) => U256::from_str_radix(digits, 16).map_err(|e| e.to_string()),
+        None => U256::from_dec_str(text).map_err(|e| e.to_string()),
+    }
+}
+
+impl BigInt for WU256 {
+    fn to_decimal(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn to_hex(&self) -> String {
+        format!("{:#x}", self.0)
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        parse_u256(text).map(WU256)
+    }
+}
+
+impl BigInt for WI256 {
+    fn to_decimal(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn to_hex(&self) -> String {
+        let (sign, abs) = self.0.into_sign_and_abs();
+        match sign {
+            Sign::Negative => format!("-{abs:#x}"),
+            Sign::Positive => format!("{abs:#x}"),
+        }
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let (sign, abs) = match text.strip_prefix('-') {
+            Some(abs) => (Sign::Negative, abs),
+            None => (Sign::Positive, text),
+        };
+
+        I256::checked_from_sign_and_abs(sign, parse_u256(abs)?)
+            .map(WI256)
+            .ok_or_else(|| format!("integer {text} overflows int256"))
+    }
+}
+
+impl BigInt for Wu128 {
+    fn to_decimal(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn to_hex(&self) -> String {
+        format!("{:#x}", self.0)
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        match hex_digits(text) {
+            Some(digits) => u128::from_str_radix(digits, 16),
+            None => text.parse(),
+        }
+        .map(Wu128)
+        .map_err(|e| format!("invalid uint128 {text}: {e}"))
+    }
+}
+
+/// Serializes a big integer field as a `0x` prefixed hex string, with
+/// `#[serde(with = "eth_logs::types::encoding::hex")]`.
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<T: BigInt, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_hex())
+    }
+
+    pub fn deserialize<'de, T: BigInt, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        T::parse(&text).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes a big integer field as a decimal string, with
+/// `#[serde(with = "eth_logs::types::encoding::decimal")]`.
+pub mod decimal {
+    use super::*;
+
+    pub fn serialize<T: BigInt, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_decimal())
+    }
+
+    pub fn deserialize<'de, T: BigInt, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        T::parse(&text).map_err(de::Error::custom)
+    }
+}
+
+macro_rules! big_int_serde {
+    ($($wrapper:ty),*) => {
+        $(
+            /// Serialized as a decimal string by default.
+            impl Serialize for $wrapper {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    decimal::serialize(self, serializer)
+                }
+            }
+
+            /// Deserialized from a decimal or `0x` prefixed hex string.
+            impl<'de> Deserialize<'de> for $wrapper {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    decimal::deserialize(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+big_int_serde!(WU256, WI256, Wu128);
+
+/// Serialized as an EIP-55 checksummed hex string.
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_checksum(&self.0, None))
+    }
+}
+
+/// Deserialized from a hex string, whose checksum is checked if it has mixed case.
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        let address = H160::from_str(&text).map_err(de::Error::custom)?;
+
+        let digits = hex_digits(&text).unwrap_or(&text);
+        let mixed_case = digits.chars().any(|c| c.is_ascii_uppercase())
+            && digits.chars().any(|c| c.is_ascii_lowercase());
+        if mixed_case && to_checksum(&address, None).trim_start_matches("0x") != digits {
+            return Err(de::Error::custom(format!(
+                "invalid address checksum {text}"
+            )));
+        }
+
+        Ok(Address(address))
+    }
+}
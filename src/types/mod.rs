@@ -1,36 +1,44 @@
 use bytes::{BufMut, BytesMut};
-use ethers::{
-    prelude::Abigen,
-    types::{H160, I256, U256},
-};
-use eyre::Result;
+use ethers::types::{H160, I256, U256};
 use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use rusqlite::types::{FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
 use std::error::Error;
 use std::ops::Deref;
-use tokio_postgres::Row;
 
-pub mod pool_types;
-use crate::types::pool_types::usdc_weth_pool::*;
+pub mod encoding;
 
+/// The bindings of every ABI under `abi/`, generated into `OUT_DIR` by the build script.
+pub mod bindings {
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
 
-// use from instead of into
-
-pub fn generate_types() -> Result<()> {
-    let abi_source = "./abi/usdc_weth.abi";
-    let out_file = "./src/types/pool_types.rs";
+/// The bindings of the pool.
+pub mod pool_types {
+    pub use super::bindings::usdc_weth_pool::*;
+}
 
-    Abigen::new("USDC_WETH_POOL", abi_source)?
-        .generate()?
-        .write_to_file(out_file)?;
+use crate::error::IndexerError;
 
-    println!("Successfully created types file");
+// use from instead of into
 
-    Ok(())
+/// Copies a PostgreSQL value of exactly `N` bytes.
+///
+/// # Arguments
+///
+/// - `name`: The name of the decoded type, for the error.
+/// - `raw`: The raw value.
+fn fixed<const N: usize>(name: &'static str, raw: &[u8]) -> Result<[u8; N], IndexerError> {
+    raw.try_into().map_err(|_| IndexerError::InvalidValue {
+        name,
+        expected: N,
+        actual: raw.len(),
+    })
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Address(H160);
 
+#[allow(clippy::from_over_into)]
 impl Into<Address> for H160 {
     fn into(self) -> Address {
         Address(self)
@@ -47,8 +55,8 @@ impl ToSql for Address {
         Ok(IsNull::No)
     }
 
-    fn accepts(_ty: &Type) -> bool {
-        true
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BYTEA)
     }
 
     to_sql_checked!();
@@ -56,11 +64,11 @@ impl ToSql for Address {
 
 impl<'a> FromSql<'a> for Address {
     fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
-        Ok(Address(H160::from_slice(&raw)))
+        Ok(Address(H160(fixed("address", raw)?)))
     }
 
-    fn accepts(_ty: &Type) -> bool {
-        true
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BYTEA)
     }
 }
 
@@ -72,10 +80,30 @@ impl Deref for Address {
     }
 }
 
+impl rusqlite::ToSql for Address {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.as_bytes()))
+    }
+}
+
+impl rusqlite::types::FromSql for Address {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let raw = value.as_blob()?;
+        if raw.len() != 20 {
+            return Err(FromSqlError::InvalidBlobSize {
+                expected_size: 20,
+                blob_size: raw.len(),
+            });
+        }
+        Ok(Address(H160::from_slice(raw)))
+    }
+}
+
 /// Wrapped type for I256
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WI256(I256);
 
+#[allow(clippy::from_over_into)]
 impl Into<WI256> for I256 {
     fn into(self) -> WI256 {
         WI256(self)
@@ -94,8 +122,8 @@ impl ToSql for WI256 {
         Ok(IsNull::No)
     }
 
-    fn accepts(_ty: &Type) -> bool {
-        true
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BYTEA)
     }
 
     to_sql_checked!();
@@ -103,11 +131,13 @@ impl ToSql for WI256 {
 
 impl<'a> FromSql<'a> for WI256 {
     fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
-        Ok(WI256(I256::from_raw(U256::from_big_endian(raw))))
+        Ok(WI256(I256::from_raw(U256::from_big_endian(&fixed::<32>(
+            "int256", raw,
+        )?))))
     }
 
-    fn accepts(_ty: &Type) -> bool {
-        true
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BYTEA)
     }
 }
 
@@ -119,10 +149,32 @@ impl Deref for WI256 {
     }
 }
 
+impl rusqlite::ToSql for WI256 {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let mut bytes = [0u8; 32];
+        self.to_big_endian(&mut bytes);
+        Ok(ToSqlOutput::from(bytes.to_vec()))
+    }
+}
+
+impl rusqlite::types::FromSql for WI256 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let raw = value.as_blob()?;
+        if raw.len() != 32 {
+            return Err(FromSqlError::InvalidBlobSize {
+                expected_size: 32,
+                blob_size: raw.len(),
+            });
+        }
+        Ok(WI256(I256::from_raw(U256::from_big_endian(raw))))
+    }
+}
+
 /// Wrapped type for U256
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WU256(U256);
 
+#[allow(clippy::from_over_into)]
 impl Into<WU256> for U256 {
     fn into(self) -> WU256 {
         WU256(self)
@@ -150,11 +202,11 @@ impl ToSql for WU256 {
 
 impl<'a> FromSql<'a> for WU256 {
     fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
-        Ok(WU256(U256::from_big_endian(&raw)))
+        Ok(WU256(U256::from_big_endian(&fixed::<32>("uint256", raw)?)))
     }
 
-    fn accepts(_ty: &Type) -> bool {
-        true
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BYTEA)
     }
 }
 
@@ -166,10 +218,32 @@ impl Deref for WU256 {
     }
 }
 
+impl rusqlite::ToSql for WU256 {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let mut bytes = [0u8; 32];
+        self.to_big_endian(&mut bytes);
+        Ok(ToSqlOutput::from(bytes.to_vec()))
+    }
+}
+
+impl rusqlite::types::FromSql for WU256 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let raw = value.as_blob()?;
+        if raw.len() != 32 {
+            return Err(FromSqlError::InvalidBlobSize {
+                expected_size: 32,
+                blob_size: raw.len(),
+            });
+        }
+        Ok(WU256(U256::from_big_endian(raw)))
+    }
+}
+
 /// Wrapped type for U256
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Wu128(u128);
 
+#[allow(clippy::from_over_into)]
 impl Into<Wu128> for u128 {
     fn into(self) -> Wu128 {
         Wu128(self)
@@ -195,13 +269,11 @@ impl ToSql for Wu128 {
 
 impl<'a> FromSql<'a> for Wu128 {
     fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
-        let mut bytes: [u8; 16] = [0; 16];
-        bytes.copy_from_slice(&raw[..16]);
-        Ok(Wu128(u128::from_be_bytes(bytes)))
+        Ok(Wu128(u128::from_be_bytes(fixed("uint128", raw)?)))
     }
 
-    fn accepts(_ty: &Type) -> bool {
-        true
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::BYTEA)
     }
 }
 
@@ -213,56 +285,19 @@ impl Deref for Wu128 {
     }
 }
 
-impl From<Row> for SwapFilter {
-    fn from(row: Row) -> Self {
-        Self {
-            sender: *row.get::<_, Address>("sender_address"),
-            recipient: *row.get::<_, Address>("receiver_address"),
-            amount_0: *row.get::<_, WI256>("amount0"),
-            amount_1: *row.get::<_, WI256>("amount1"),
-            sqrt_price_x96: *row.get::<_, WU256>("sqrt_price_x96"),
-            liquidity: *row.get::<_, Wu128>("liquidity"),
-            tick: row.get("tick"),
-        }
-    }
-}
-
-impl From<Row> for MintFilter {
-    fn from(row: Row) -> Self {
-        Self {
-            sender: *row.get::<_, Address>("sender_address"),
-            owner: *row.get::<_, Address>("owner_address"),
-            tick_lower: row.get("tick_lower"),
-            tick_upper: row.get("tick_upper"),
-            amount: *row.get::<_, Wu128>("amount"),
-            amount_0: *row.get::<_, WU256>("amount0"),
-            amount_1: *row.get::<_, WU256>("amount1"),
-        }
+impl rusqlite::ToSql for Wu128 {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_be_bytes().to_vec()))
     }
 }
 
-impl From<Row> for BurnFilter {
-    fn from(row: Row) -> Self {
-        Self {
-            owner: *row.get::<_, Address>("owner_address"),
-            tick_lower: row.get("tick_lower"),
-            tick_upper: row.get("tick_upper"),
-            amount: *row.get::<_, Wu128>("amount"),
-            amount_0: *row.get::<_, WU256>("amount0"),
-            amount_1: *row.get::<_, WU256>("amount1"),
-        }
-    }
-}
-
-impl From<Row> for FlashFilter {
-    fn from(row: Row) -> Self {
-        Self {
-            sender: *row.get::<_, Address>("sender_address"),
-            recipient: *row.get::<_, Address>("receiver_address"),
-            amount_0: *row.get::<_, WU256>("amount0"),
-            amount_1: *row.get::<_, WU256>("amount1"),
-            paid_0: *row.get::<_, WU256>("paid0"),
-            paid_1: *row.get::<_, WU256>("paid1"),
-        }
+impl rusqlite::types::FromSql for Wu128 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let raw = value.as_blob()?;
+        let bytes: [u8; 16] = raw.try_into().map_err(|_| FromSqlError::InvalidBlobSize {
+            expected_size: 16,
+            blob_size: raw.len(),
+        })?;
+        Ok(Wu128(u128::from_be_bytes(bytes)))
     }
 }
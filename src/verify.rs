@@ -0,0 +1,601 @@
+use ethers::{
+    abi::RawLog,
+    prelude::LogMeta,
+    providers::{Provider, Ws},
+    types::{Address, H256},
+};
+use eyre::{eyre, Result};
+use log::info;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::config::Settings;
+use crate::database::DB;
+use crate::decode::{dead_letter, decode_log, log_meta};
+use crate::export::{Cell, Columns, EventKind, LogRecord};
+use crate::ingest::{BlockTimes, Source};
+use crate::query::{Cursor, EventQuery, Page};
+use crate::storage::Storage;
+use crate::types::pool_types::usdc_weth_pool::*;
+
+/// Identifies a log by its block, transaction and position in the block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LogKey {
+    pub block_number: u64,
+    pub transaction_hash: H256,
+    pub log_index: u64,
+}
+
+impl LogKey {
+    fn from_meta(meta: &LogMeta) -> Self {
+        Self {
+            block_number: meta.block_number.as_u64(),
+            transaction_hash: meta.transaction_hash,
+            log_index: meta.log_index.as_u64(),
+        }
+    }
+}
+
+/// An event fetched from the node.
+#[derive(Debug, Clone)]
+pub struct ChainEvent {
+    pub meta: LogMeta,
+    pub event: USDC_WETH_POOLEvents,
+}
+
+/// A log of the pools that failed to decode.
+#[derive(Debug, Clone)]
+pub struct UndecodableLog {
+    pub meta: LogMeta,
+    pub log: RawLog,
+    pub error: String,
+}
+
+/// An event read back from the database.
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    /// The table the event was read from.
+    pub kind: EventKind,
+
+    /// The id of the event row.
+    pub id: i32,
+
+    pub log: LogRecord,
+    pub event: USDC_WETH_POOLEvents,
+}
+
+/// A column whose stored value differs from the value on chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub column: &'static str,
+    pub stored: Cell,
+    pub chain: Cell,
+}
+
+/// An event stored with values that differ from the chain.
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    pub stored: StoredEvent,
+    pub chain: ChainEvent,
+    pub fields: Vec<FieldDiff>,
+}
+
+/// The differences between the chain and the database over a block range.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    /// Events on chain that are not stored.
+    pub missing: Vec<ChainEvent>,
+
+    /// Stored events that are not on chain.
+    pub extra: Vec<StoredEvent>,
+
+    /// Events stored with different values.
+    pub mismatched: Vec<Mismatch>,
+
+    /// Logs on chain that failed to decode, kept as dead letters by a repair.
+    pub undecodable: Vec<UndecodableLog>,
+}
+
+impl Report {
+    /// Returns whether the database matches the chain.
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty()
+            && self.extra.is_empty()
+            && self.mismatched.is_empty()
+            && self.undecodable.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for missing in &self.missing {
+            writeln!(
+                f,
+                "missing  {} block {} tx {:?} log {}",
+                event_name(&missing.event),
+                missing.meta.block_number,
+                missing.meta.transaction_hash,
+                missing.meta.log_index
+            )?;
+        }
+        for extra in &self.extra {
+            writeln!(
+                f,
+                "extra    {} id {} block {} tx {}",
+                extra.kind.table(),
+                extra.id,
+                extra.log.block_number.unwrap_or_default(),
+                extra
+                    .log
+                    .transaction_hash
+                    .map_or("unknown".into(), |h| format!("{h:?}"))
+            )?;
+        }
+        for mismatch in &self.mismatched {
+            writeln!(
+                f,
+                "mismatch {} id {} block {} tx {:?} log {}",
+                mismatch.stored.kind.table(),
+                mismatch.stored.id,
+                mismatch.chain.meta.block_number,
+                mismatch.chain.meta.transaction_hash,
+                mismatch.chain.meta.log_index
+            )?;
+            for field in &mismatch.fields {
+                writeln!(
+                    f,
+                    "    {}: stored {}, chain {}",
+                    field.column,
+                    cell(&field.stored),
+                    cell(&field.chain)
+                )?;
+            }
+        }
+
+        for undecodable in &self.undecodable {
+            writeln!(
+                f,
+                "undecodable block {} tx {:?} log {}: {}",
+                undecodable.meta.block_number,
+                undecodable.meta.transaction_hash,
+                undecodable.meta.log_index,
+                undecodable.error
+            )?;
+        }
+
+        write!(
+            f,
+            "{} missing, {} extra, {} mismatched, {} undecodable",
+            self.missing.len(),
+            self.extra.len(),
+            self.mismatched.len(),
+            self.undecodable.len()
+        )
+    }
+}
+
+/// Verifies the stored events of a block range against the chain, and optionally repairs them.
+///
+/// The logs of the pools are fetched through `eth_getLogs`, decoded and compared field by field
+/// with the stored rows. Logs that fail to decode are reported, and kept as dead letters by a
+/// repair, without stopping the verification.
+///
+/// # Arguments
+///
+/// - `db`: A reference to the PostgreSQL database instance.
+/// - `from`: The first block to verify.
+/// - `to`: The last block to verify.
+/// - `repair`: Whether to rewrite the database to match the chain.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the differences found before any repair.
+pub async fn verify(db: &DB, from: u64, to: u64, repair: bool) -> Result<Report> {
+    let settings = Settings::new()?;
+    settings.validate_rpc()?;
+    let source = Source::pool(&settings)?;
+    let provider = source.connect().await?;
+
+    let (chain, undecodable) = fetch_events(&provider, &source, from, to).await?;
+    let stored = stored_events(db, from, to).await?;
+    let report = Report {
+        undecodable,
+        ..diff(chain, stored)
+    };
+
+    if repair && !report.is_clean() {
+        repair_events(db, &provider, &report).await?;
+    }
+
+    Ok(report)
+}
+
+/// Fetches the indexed events of the pools in a block range through `eth_getLogs`.
+///
+/// The range is split into requests of `batch_size` blocks to stay below the limits of most
+/// providers.
+///
+/// # Arguments
+///
+/// - `provider`: The node the logs are read from.
+/// - `source`: The logs of the pools.
+/// - `from`: The first block to fetch.
+/// - `to`: The last block to fetch.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the swap, mint, burn and flash events of the range, and the
+/// logs that failed to decode.
+pub async fn fetch_events(
+    provider: &Provider<Ws>,
+    source: &Source,
+    from: u64,
+    to: u64,
+) -> Result<(Vec<ChainEvent>, Vec<UndecodableLog>)> {
+    let mut events = Vec::new();
+    let mut undecodable = Vec::new();
+
+    for log in source.fetch(provider, from, to).await? {
+        let meta = log_meta(&log).ok_or_else(|| eyre!("the node returned a pending log"))?;
+        let log = RawLog::from(log);
+
+        match decode_log(&log) {
+            Ok(event) if kind(&event).is_some() => events.push(ChainEvent { meta, event }),
+            Ok(_) => {}
+            Err(e) => undecodable.push(UndecodableLog {
+                meta,
+                log,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    Ok((events, undecodable))
+}
+
+/// Reads the stored events of every event table in a block range.
+///
+/// # Arguments
+///
+/// - `db`: A reference to the PostgreSQL database instance.
+/// - `from`: The first block to read.
+/// - `to`: The last block to read.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the stored events, ordered by table and id.
+pub async fn stored_events(db: &DB, from: u64, to: u64) -> Result<Vec<StoredEvent>> {
+    let mut query = EventQuery {
+        from_block: Some(from),
+        to_block: Some(to),
+        ..Default::default()
+    };
+
+    let mut events = Vec::new();
+    for kind in [
+        EventKind::Swap,
+        EventKind::Mint,
+        EventKind::Burn,
+        EventKind::Flash,
+    ] {
+        query.after = None;
+
+        loop {
+            let (page, next) = match kind {
+                EventKind::Swap => stored_page(kind, db.swaps(&query).await?),
+                EventKind::Mint => stored_page(kind, db.mints(&query).await?),
+                EventKind::Burn => stored_page(kind, db.burns(&query).await?),
+                EventKind::Flash => stored_page(kind, db.flashes(&query).await?),
+            };
+            events.extend(page);
+
+            match next {
+                Some(cursor) => query.after = Some(cursor),
+                None => break,
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+fn stored_page<T: Into<USDC_WETH_POOLEvents>>(
+    kind: EventKind,
+    page: Page<T>,
+) -> (Vec<StoredEvent>, Option<Cursor>) {
+    let events = page
+        .items
+        .into_iter()
+        .map(|item| StoredEvent {
+            kind,
+            id: item.id,
+            log: item.log,
+            event: item.event.into(),
+        })
+        .collect();
+
+    (events, page.next)
+}
+
+/// Compares the events of the chain with the stored events of the same block range.
+///
+/// Stored events are matched by block, transaction and log index. Rows indexed before the log
+/// index was stored are matched with the unmatched events of the same kind in their
+/// transaction, in order.
+///
+/// # Arguments
+///
+/// - `chain`: The events fetched from the node.
+/// - `stored`: The events read from the database.
+///
+/// # Returns
+///
+/// Returns the missing, extra and mismatched events.
+pub fn diff(chain: Vec<ChainEvent>, stored: Vec<StoredEvent>) -> Report {
+    let mut unmatched: HashMap<LogKey, ChainEvent> = chain
+        .into_iter()
+        .map(|event| (LogKey::from_meta(&event.meta), event))
+        .collect();
+
+    let mut report = Report::default();
+    let mut legacy = Vec::new();
+
+    for stored in stored {
+        let key = match (stored.log.block_number, stored.log.transaction_hash) {
+            (Some(block_number), Some(transaction_hash)) => {
+                stored.log.log_index.map(|log_index| LogKey {
+                    block_number,
+                    transaction_hash,
+                    log_index,
+                })
+            }
+            _ => None,
+        };
+
+        match key {
+            Some(key) => match unmatched.remove(&key) {
+                Some(chain) => compare(&mut report, stored, chain),
+                None => report.extra.push(stored),
+            },
+            None => legacy.push(stored),
+        }
+    }
+
+    // Legacy rows are ordered by id, which follows the order of the logs
+    for stored in legacy {
+        let key = unmatched
+            .iter()
+            .filter(|(key, chain)| {
+                Some(key.transaction_hash) == stored.log.transaction_hash
+                    && kind(&chain.event) == Some(stored.kind)
+            })
+            .map(|(key, _)| *key)
+            .min();
+
+        match key.and_then(|key| unmatched.remove(&key)) {
+            Some(chain) => compare(&mut report, stored, chain),
+            None => report.extra.push(stored),
+        }
+    }
+
+    report.missing = unmatched.into_values().collect();
+    report
+        .missing
+        .sort_by_key(|event| LogKey::from_meta(&event.meta));
+
+    report
+}
+
+/// Adds the stored event to the report if any of its fields differ from the chain.
+fn compare(report: &mut Report, stored: StoredEvent, chain: ChainEvent) {
+    let mut fields = Vec::new();
+
+    if stored.log.address != Some(chain.meta.address) {
+        fields.push(FieldDiff {
+            column: "address",
+            stored: stored.log.address.map_or(Cell::Null, cell_hex),
+            chain: cell_hex(chain.meta.address),
+        });
+    }
+
+    if kind(&chain.event) != Some(stored.kind) {
+        fields.push(FieldDiff {
+            column: "event",
+            stored: Cell::Text(stored.kind.table().into()),
+            chain: Cell::Text(event_name(&chain.event).into()),
+        });
+    } else {
+        let chain_columns = event_columns(&chain.event);
+        for ((column, stored), (_, chain)) in
+            event_columns(&stored.event).into_iter().zip(chain_columns)
+        {
+            if stored != chain {
+                fields.push(FieldDiff {
+                    column,
+                    stored,
+                    chain,
+                });
+            }
+        }
+    }
+
+    if !fields.is_empty() {
+        report.mismatched.push(Mismatch {
+            stored,
+            chain,
+            fields,
+        });
+    }
+}
+
+/// Rewrites the database to match the chain, in a single transaction.
+///
+/// Extra events are deleted, missing events are inserted and mismatched events are deleted
+/// and inserted again with the values of the chain. The blocks of the inserted events are
+/// recorded with their timestamp first. Undecodable logs are kept as dead letters, unless they
+/// already are.
+///
+/// # Arguments
+///
+/// - `db`: A reference to the PostgreSQL database instance.
+/// - `times`: The timestamps of the blocks, usually the node.
+/// - `report`: The differences returned by `diff`.
+///
+/// # Returns
+///
+/// Returns a `Result` indicating the success or failure of the repair.
+pub async fn repair_events(db: &DB, times: &dyn BlockTimes, report: &Report) -> Result<()> {
+    db.client.batch_execute("BEGIN").await?;
+
+    let result = async {
+        for stored in report
+            .extra
+            .iter()
+            .chain(report.mismatched.iter().map(|m| &m.stored))
+        {
+            db.delete_event(stored).await?;
+        }
+
+        for chain in report
+            .missing
+            .iter()
+            .chain(report.mismatched.iter().map(|m| &m.chain))
+        {
+            let block_hash = chain.meta.block_hash;
+            let timestamp = times.block_time(block_hash).await?;
+            db.upsert_block(chain.meta.block_number.as_u64(), block_hash, timestamp)
+                .await?;
+            db.insert_event(&chain.meta, &chain.event).await?;
+        }
+
+        for undecodable in &report.undecodable {
+            if !db.has_dead_letter(&undecodable.meta).await? {
+                dead_letter(
+                    db,
+                    &undecodable.meta,
+                    Some(&undecodable.log),
+                    &undecodable.error,
+                )
+                .await?;
+            }
+        }
+
+        Ok::<_, eyre::Report>(())
+    }
+    .await;
+
+    match result {
+        Ok(()) => {
+            db.commit_transaction().await?;
+            info!(
+                "Repaired {} missing, {} extra and {} mismatched events, and {} undecodable logs",
+                report.missing.len(),
+                report.extra.len(),
+                report.mismatched.len(),
+                report.undecodable.len()
+            );
+            Ok(())
+        }
+        Err(e) => {
+            db.rollback_transaction().await?;
+            Err(e)
+        }
+    }
+}
+
+impl DB {
+    /// Deletes a stored event together with its `ethereum_logs` row and its rollup contribution.
+    ///
+    /// # Arguments
+    ///
+    /// - `event`: The event to delete.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the success or failure of the deletion.
+    pub async fn delete_event(&self, event: &StoredEvent) -> Result<()> {
+        self.reverse_rollups(event.kind, "e.id = $1", &[&event.id])
+            .await?;
+
+        self.client
+            .execute(
+                &format!("DELETE FROM {} WHERE id = $1", event.kind.table()),
+                &[&event.id],
+            )
+            .await?;
+
+        // Partitioned tables have no foreign keys, so the log row is deleted explicitly
+        if let Some(id) = event.log.ethereum_log_id {
+            self.client
+                .execute("DELETE FROM ethereum_logs WHERE id = $1", &[&id])
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether a dead letter is kept for a log.
+    ///
+    /// # Arguments
+    ///
+    /// - `meta`: LogMeta of the log.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing whether the log has a dead letter.
+    pub async fn has_dead_letter(&self, meta: &LogMeta) -> Result<bool> {
+        let row = self
+            .client
+            .query_opt(
+                "
+               SELECT 1 FROM dead_letters
+               WHERE block_number = $1 AND transaction_hash = $2 AND log_index = $3
+               ",
+                &[
+                    &&meta.block_number.as_u64().to_be_bytes()[..],
+                    &meta.transaction_hash.as_bytes(),
+                    &(meta.log_index.as_u64() as i64),
+                ],
+            )
+            .await?;
+
+        Ok(row.is_some())
+    }
+}
+
+/// The table of an event, or `None` for events that are not indexed.
+fn kind(event: &USDC_WETH_POOLEvents) -> Option<EventKind> {
+    match event {
+        USDC_WETH_POOLEvents::SwapFilter(_) => Some(EventKind::Swap),
+        USDC_WETH_POOLEvents::MintFilter(_) => Some(EventKind::Mint),
+        USDC_WETH_POOLEvents::BurnFilter(_) => Some(EventKind::Burn),
+        USDC_WETH_POOLEvents::FlashFilter(_) => Some(EventKind::Flash),
+        _ => None,
+    }
+}
+
+fn event_name(event: &USDC_WETH_POOLEvents) -> &'static str {
+    kind(event).map_or("unindexed", |kind| kind.table())
+}
+
+fn event_columns(event: &USDC_WETH_POOLEvents) -> Vec<(&'static str, Cell)> {
+    match event {
+        USDC_WETH_POOLEvents::SwapFilter(e) => e.columns(),
+        USDC_WETH_POOLEvents::MintFilter(e) => e.columns(),
+        USDC_WETH_POOLEvents::BurnFilter(e) => e.columns(),
+        USDC_WETH_POOLEvents::FlashFilter(e) => e.columns(),
+        _ => Vec::new(),
+    }
+}
+
+fn cell_hex(address: Address) -> Cell {
+    Cell::Text(format!("{address:?}"))
+}
+
+fn cell(cell: &Cell) -> String {
+    match cell {
+        Cell::Int(n) => n.to_string(),
+        Cell::Text(s) => s.clone(),
+        Cell::Null => "null".into(),
+    }
+}
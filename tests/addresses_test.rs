@@ -0,0 +1,46 @@
+use eth_logs::addresses::{address_schema, AddressCache};
+use ethers::types::H160;
+use std::num::NonZeroUsize;
+
+#[test]
+fn views_restore_address_columns() {
+    let sql = address_schema();
+
+    assert!(sql.contains("CREATE OR REPLACE VIEW swap_events AS"));
+    assert!(sql.contains("a_receiver.address AS receiver_address"));
+    assert!(sql.contains("JOIN addresses a_owner ON a_owner.id = e.owner_id"));
+    assert!(sql.contains("ALTER TABLE burn_logs DROP COLUMN owner_address"));
+}
+
+#[test]
+fn cache_keeps_ids_once_committed() {
+    let mut cache = AddressCache::default();
+    let address = H160::repeat_byte(0x11);
+
+    cache.insert(address, 1);
+    assert_eq!(Some(1), cache.get(&address));
+
+    cache.commit();
+    cache.forget();
+    assert_eq!(Some(1), cache.get(&address));
+}
+
+#[test]
+fn cache_forgets_rolled_back_ids() {
+    let mut cache = AddressCache::new(NonZeroUsize::new(1).unwrap());
+    let (first, second) = (H160::repeat_byte(0x11), H160::repeat_byte(0x22));
+
+    // A rolled back transaction or savepoint leaves only the committed ids
+    cache.insert(first, 1);
+    cache.commit();
+    cache.insert(second, 2);
+    cache.forget();
+    assert_eq!(Some(1), cache.get(&first));
+    assert_eq!(None, cache.get(&second));
+
+    // Committed ids are evicted past the capacity
+    cache.insert(second, 2);
+    cache.commit();
+    assert_eq!(None, cache.get(&first));
+    assert_eq!(Some(2), cache.get(&second));
+}
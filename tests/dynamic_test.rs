@@ -0,0 +1,87 @@
+use eth_logs::dynamic::{decode_dynamic, DynamicContract};
+use eth_logs::indexed::ColumnKind;
+use ethers::abi::{Abi, RawLog, Token};
+use ethers::types::{H160, H256, U256};
+
+const ERC20_ABI: &str = r#"[
+    {"type":"event","name":"Transfer","anonymous":false,"inputs":[
+        {"name":"from","type":"address","indexed":true},
+        {"name":"to","type":"address","indexed":true},
+        {"name":"value","type":"uint256","indexed":false}]},
+    {"type":"event","name":"Approval","anonymous":false,"inputs":[
+        {"name":"owner","type":"address","indexed":true},
+        {"name":"spender","type":"address","indexed":true},
+        {"name":"value","type":"uint256","indexed":false}]},
+    {"type":"event","name":"Memo","anonymous":false,"inputs":[
+        {"name":"timestamp","type":"uint64","indexed":false},
+        {"name":"","type":"string","indexed":true},
+        {"name":"tags","type":"uint8[]","indexed":false}]}
+]"#;
+
+fn erc20() -> DynamicContract {
+    let abi: Abi = serde_json::from_str(ERC20_ABI).unwrap();
+    DynamicContract::from_abi("ERC20", &abi, vec![H160::repeat_byte(0x11)]).unwrap()
+}
+
+#[test]
+fn tables_follow_the_abi() {
+    let contract = erc20();
+
+    let transfer = contract
+        .events
+        .iter()
+        .find(|e| e.event.name == "Transfer")
+        .unwrap();
+    assert_eq!(transfer.table, "erc20_transfer_logs");
+    assert_eq!(transfer.view(), "erc20_transfer_events");
+
+    let sql = transfer.create_table();
+    assert!(sql.contains("CREATE TABLE IF NOT EXISTS erc20_transfer_logs ("));
+    assert!(sql.contains("\"from_id\" INT NOT NULL REFERENCES addresses (id),"));
+    assert!(sql.contains("\"value\" BYTEA NOT NULL,"));
+    assert!(sql.contains("a_to.address AS to_address"));
+
+    let memo = contract
+        .events
+        .iter()
+        .find(|e| e.event.name == "Memo")
+        .unwrap();
+    let columns: Vec<(&str, ColumnKind)> = memo
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), c.kind))
+        .collect();
+    assert_eq!(
+        columns,
+        [
+            ("arg_timestamp", ColumnKind::Uint128),
+            ("arg1", ColumnKind::Bytes),
+            ("tags", ColumnKind::Text),
+        ]
+    );
+}
+
+#[test]
+fn logs_are_decoded_by_address_and_selector() {
+    let contract = erc20();
+    let contracts = [contract];
+    let transfer = contracts[0]
+        .events
+        .iter()
+        .find(|e| e.event.name == "Transfer")
+        .unwrap();
+
+    let from = H160::repeat_byte(0xaa);
+    let to = H160::repeat_byte(0xbb);
+    let log = RawLog {
+        topics: vec![transfer.event.signature(), H256::from(from), H256::from(to)],
+        data: ethers::abi::encode(&[Token::Uint(U256::from(1000))]),
+    };
+
+    let (event, decoded) = decode_dynamic(&contracts, H160::repeat_byte(0x11), &log).unwrap();
+    assert_eq!(event.table, "erc20_transfer_logs");
+    assert_eq!(decoded.params[2].value, Token::Uint(U256::from(1000)));
+    assert_eq!(event.values(decoded).unwrap().len(), 3);
+
+    assert!(decode_dynamic(&contracts, H160::repeat_byte(0x22), &log).is_err());
+}
@@ -0,0 +1,80 @@
+use eth_logs::envelope::Envelope;
+use eth_logs::types::encoding::Encoding;
+use eth_logs::types::pool_types::usdc_weth_pool::*;
+use eth_logs::types::{Address as Wrapped, WI256};
+use ethers::{
+    prelude::LogMeta,
+    types::{Address, H256, I256, U256, U64},
+};
+use serde_json::{json, Value};
+
+fn swap_envelope() -> Envelope {
+    let meta = LogMeta {
+        address: "0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640".parse().unwrap(),
+        block_number: U64::from(18_000_000),
+        block_hash: H256::repeat_byte(0xaa),
+        transaction_hash: H256::repeat_byte(0xbb),
+        transaction_index: U64::from(4),
+        log_index: U256::from(12),
+    };
+    let event = USDC_WETH_POOLEvents::SwapFilter(SwapFilter {
+        sender: Address::repeat_byte(0x11),
+        recipient: Address::repeat_byte(0x22),
+        amount_0: I256::from(-255),
+        amount_1: I256::from(4096),
+        sqrt_price_x96: U256::from(1) << 96,
+        liquidity: 10,
+        tick: -200_000,
+    });
+
+    Envelope::new(meta, event).unwrap()
+}
+
+#[test]
+fn envelope_round_trips_in_both_encodings() {
+    let envelope = swap_envelope();
+
+    let decimal = serde_json::to_value(&envelope).unwrap();
+    assert_eq!(
+        json!({
+            "type": "swap",
+            "sender": "0x1111111111111111111111111111111111111111",
+            "recipient": "0x2222222222222222222222222222222222222222",
+            "amount0": "-255",
+            "amount1": "4096",
+            "sqrt_price_x96": "79228162514264337593543950336",
+            "liquidity": "10",
+            "tick": -200000
+        }),
+        decimal["event"]
+    );
+    assert_eq!(
+        Value::from("0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640"),
+        decimal["pool"]
+    );
+
+    let hex = serde_json::to_value(envelope.clone().with_encoding(Encoding::Hex)).unwrap();
+    assert_eq!(Value::from("-0xff"), hex["event"]["amount0"]);
+    assert_eq!(Value::from("0xa"), hex["event"]["liquidity"]);
+
+    for value in [decimal, hex] {
+        let decoded: Envelope = serde_json::from_value(value).unwrap();
+        assert_eq!(envelope, decoded);
+    }
+}
+
+#[test]
+fn wrappers_check_their_text() {
+    let checksummed = r#""0x88e6A0c2dDD26FEEb64F039a2c41296FcB3f5640""#;
+    let address: Wrapped = serde_json::from_str(checksummed).unwrap();
+    assert_eq!(checksummed, serde_json::to_string(&address).unwrap());
+
+    let lowercase = checksummed.to_lowercase();
+    assert!(serde_json::from_str::<Wrapped>(&lowercase).is_ok());
+    let bad_checksum = checksummed.replace("0x88e6A", "0x88E6a");
+    assert!(serde_json::from_str::<Wrapped>(&bad_checksum).is_err());
+
+    let value: WI256 = serde_json::from_str(r#""-0x10""#).unwrap();
+    assert_eq!(I256::from(-16), *value);
+    assert!(serde_json::from_str::<WI256>(r#""12abc""#).is_err());
+}
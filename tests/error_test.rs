@@ -0,0 +1,41 @@
+use eth_logs::decode::decode_log;
+use eth_logs::error::{is_transient, IndexerError};
+use eth_logs::types::{Address, Wu128, WU256};
+use ethers::abi::RawLog;
+use postgres_types::{FromSql, Type};
+use std::io;
+
+#[test]
+fn wrappers_reject_invalid_values() {
+    let err = Wu128::from_sql(&Type::BYTEA, &[0; 8]).unwrap_err();
+    assert_eq!(
+        "invalid uint128 value: expected 16 bytes, got 8",
+        err.to_string()
+    );
+
+    assert!(Address::from_sql(&Type::BYTEA, &[0; 32]).is_err());
+    assert!(WU256::from_sql(&Type::BYTEA, &[0; 33]).is_err());
+    assert_eq!(
+        u128::MAX,
+        *Wu128::from_sql(&Type::BYTEA, &[0xff; 16]).unwrap()
+    );
+
+    assert!(<Address as FromSql>::accepts(&Type::BYTEA));
+    assert!(!<Address as FromSql>::accepts(&Type::INT4));
+    assert!(!<Wu128 as FromSql>::accepts(&Type::TEXT));
+}
+
+#[test]
+fn errors_are_classified() {
+    let decode = decode_log(&RawLog {
+        topics: Vec::new(),
+        data: Vec::new(),
+    })
+    .unwrap_err();
+    assert!(matches!(decode, IndexerError::Decode(_)));
+    assert!(!decode.is_transient());
+    assert!(!is_transient(&eyre::Report::new(decode)));
+
+    let dropped = io::Error::new(io::ErrorKind::ConnectionReset, "connection reset");
+    assert!(is_transient(&eyre::Report::new(dropped)));
+}
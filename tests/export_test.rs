@@ -0,0 +1,159 @@
+use ::parquet::file::reader::{FileReader, SerializedFileReader};
+use eth_logs::{
+    envelope::Envelope,
+    export::{csv, ndjson, parquet, EventKind, ExportFilter, Table, TableWriter, Tables},
+    types::pool_types::usdc_weth_pool::*,
+};
+use ethers::{
+    prelude::LogMeta,
+    types::{Address, H256, I256, U256, U64},
+};
+use std::fs;
+
+fn tables() -> Tables {
+    let meta = LogMeta {
+        address: Address::repeat_byte(0x11),
+        block_number: U64::from(42),
+        block_hash: H256::zero(),
+        transaction_hash: H256::repeat_byte(0xab),
+        transaction_index: U64::zero(),
+        log_index: U256::zero(),
+    };
+
+    let swap = SwapFilter {
+        sender: Address::repeat_byte(0x22),
+        amount_0: I256::from(-1),
+        sqrt_price_x96: U256::MAX,
+        tick: -3,
+        ..Default::default()
+    };
+
+    let mut tables = Tables::default();
+    tables.push(&meta, &USDC_WETH_POOLEvents::SwapFilter(swap));
+    tables.push(
+        &meta,
+        &USDC_WETH_POOLEvents::MintFilter(MintFilter::default()),
+    );
+    tables
+}
+
+#[test]
+fn csv_export_renders_hex_and_decimal() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_csv_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let filter = ExportFilter {
+        event: Some(EventKind::Swap),
+        ..Default::default()
+    };
+    csv::write_tables(&tables(), &filter, &dir).unwrap();
+
+    let swaps = fs::read_to_string(dir.join("swaps.csv")).unwrap();
+    let mut lines = swaps.lines();
+    assert_eq!(
+        Some("ethereum_log_id,block_number,transaction_hash,address,sender,recipient,amount0,amount1,sqrt_price_x96,liquidity,tick"),
+        lines.next()
+    );
+    let row = lines.next().unwrap();
+    assert!(row.starts_with(&format!(
+        ",42,0x{},0x{},0x{},",
+        "ab".repeat(32),
+        "11".repeat(20),
+        "22".repeat(20)
+    )));
+    assert!(row.ends_with(&format!(",-1,0,{},0,-3", U256::MAX)));
+
+    // Only the selected event type is written
+    assert!(!dir.join("mints.csv").exists());
+    assert!(!dir.join("logs.csv").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn ndjson_export_writes_one_object_per_line() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_ndjson_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    ndjson::write_tables(&tables(), &ExportFilter::default(), &dir).unwrap();
+
+    let swaps = fs::read_to_string(dir.join("swaps.ndjson")).unwrap();
+    let line = swaps.lines().next().unwrap();
+    let swap: serde_json::Value = serde_json::from_str(line).unwrap();
+    assert_eq!(42, swap["block_number"]);
+    assert_eq!(serde_json::Value::Null, swap["ethereum_log_id"]);
+    assert_eq!("swap", swap["event"]["type"]);
+    assert_eq!("-1", swap["event"]["amount0"]);
+    assert_eq!(-3, swap["event"]["tick"]);
+
+    // Event lines are envelopes
+    let envelope: Envelope = serde_json::from_str(line).unwrap();
+    assert_eq!(U64::from(42), envelope.meta.block_number);
+
+    assert_eq!(
+        1,
+        fs::read_to_string(dir.join("mints.ndjson"))
+            .unwrap()
+            .lines()
+            .count()
+    );
+    assert_eq!(
+        2,
+        fs::read_to_string(dir.join("logs.ndjson"))
+            .unwrap()
+            .lines()
+            .count()
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn parquet_export_writes_only_the_selected_event() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_parquet_export_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let filter = ExportFilter {
+        event: Some(EventKind::Mint),
+        ..Default::default()
+    };
+    parquet::write_tables(&tables(), &filter, &dir, "").unwrap();
+
+    assert!(dir.join("mints.parquet").exists());
+    assert!(!dir.join("swaps.parquet").exists());
+    assert!(!dir.join("logs.parquet").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn paged_exports_append_every_page() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_pages_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let swaps = Table::Events(EventKind::Swap);
+
+    let mut writer = Box::new(csv::CsvWriter::create(&dir, swaps).unwrap());
+    writer.write(&tables()).unwrap();
+    writer.write(&tables()).unwrap();
+    writer.finish().unwrap();
+
+    // One header, then the rows of both pages
+    let csv = fs::read_to_string(dir.join("swaps.csv")).unwrap();
+    assert_eq!(3, csv.lines().count());
+    assert_eq!(1, csv.matches("ethereum_log_id").count());
+
+    let mut writer = Box::new(parquet::ParquetWriter::create(&dir, swaps, "").unwrap());
+    writer.write(&tables()).unwrap();
+    writer.write(&Tables::default()).unwrap();
+    writer.write(&tables()).unwrap();
+    writer.finish().unwrap();
+
+    // One row group per non-empty page
+    let file = fs::File::open(dir.join("swaps.parquet")).unwrap();
+    let metadata = SerializedFileReader::new(file).unwrap().metadata().clone();
+    assert_eq!(2, metadata.num_row_groups());
+    assert_eq!(2, metadata.file_metadata().num_rows());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,140 @@
+use eth_logs::dynamic::migration;
+use eth_logs::generate::{bindings, generate, read_abi};
+use std::fs;
+use std::path::Path;
+
+const TRANSFER: &str = r#"[{"type":"event","name":"Transfer","anonymous":false,"inputs":[
+    {"name":"from","type":"address","indexed":true},
+    {"name":"to","type":"address","indexed":true},
+    {"name":"value","type":"uint256","indexed":false}]}]"#;
+
+#[test]
+fn artifacts_are_read_like_abis() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_abi_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let bare = dir.join("my_token.abi");
+    fs::write(&bare, TRANSFER).unwrap();
+    let hardhat = dir.join("Token.json");
+    fs::write(
+        &hardhat,
+        format!(r#"{{"_format":"hh-sol-artifact-1","contractName":"Vault","abi":{TRANSFER}}}"#),
+    )
+    .unwrap();
+    let foundry = dir.join("ERC20.json");
+    fs::write(
+        &foundry,
+        format!(r#"{{"abi":{TRANSFER},"bytecode":{{"object":"0x"}}}}"#),
+    )
+    .unwrap();
+
+    let bare = read_abi(&bare).unwrap();
+    assert_eq!(bare.name, "MyToken");
+    assert_eq!(read_abi(&hardhat).unwrap().name, "Vault");
+    assert_eq!(
+        read_abi(Path::new("abi/usdc_weth.abi")).unwrap().name,
+        "USDC_WETH_POOL"
+    );
+
+    let foundry = read_abi(&foundry).unwrap();
+    assert_eq!(foundry.name, "ERC20");
+    assert_eq!(foundry.abi, bare.abi);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn directories_become_module_trees() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_abis_{}", std::process::id()));
+    let out = dir.join("bindings");
+
+    // A Foundry `out` directory, with a Hardhat debug file and build info to skip
+    fs::create_dir_all(dir.join("ERC20.sol")).unwrap();
+    fs::create_dir_all(dir.join("build-info")).unwrap();
+    fs::write(
+        dir.join("ERC20.sol/ERC20.json"),
+        format!(r#"{{"abi":{TRANSFER},"bytecode":{{"object":"0x"}}}}"#),
+    )
+    .unwrap();
+    fs::write(dir.join("ERC20.sol/ERC20.dbg.json"), r#"{"buildInfo":""}"#).unwrap();
+    fs::write(dir.join("build-info/abc.json"), r#"{"id":"abc"}"#).unwrap();
+    fs::write(dir.join("pool.abi"), TRANSFER).unwrap();
+
+    generate(&dir, None, Some(&out), false, migration).unwrap();
+
+    let module = fs::read_to_string(out.join("mod.rs")).unwrap();
+    assert!(module.contains("pub mod erc20;"));
+    assert!(module.contains("pub mod pool;"));
+    assert!(fs::read_to_string(out.join("erc20.rs"))
+        .unwrap()
+        .contains("pub struct TransferFilter"));
+
+    // Each contract gets the migration of its tables next to its types
+    let sql = fs::read_to_string(out.join("erc20.sql")).unwrap();
+    assert!(sql.contains("CREATE TABLE IF NOT EXISTS erc20_transfer_logs ("));
+    assert!(sql.contains("ethereum_log_id INT REFERENCES ethereum_logs(id) ON DELETE CASCADE,"));
+    assert!(sql.contains("\"value\" BYTEA NOT NULL,"));
+    assert!(sql.contains(
+        "CREATE INDEX IF NOT EXISTS \"erc20_transfer_logs_from_idx\" ON erc20_transfer_logs (\"from_id\");"
+    ));
+    assert!(!sql.contains("erc20_transfer_logs_value_idx"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn check_detects_stale_types() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_check_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let abi = dir.join("token.abi");
+    let out = dir.join("token.rs");
+    fs::write(&abi, TRANSFER).unwrap();
+
+    assert!(generate(&abi, None, Some(&out), true, migration).is_err());
+    generate(&abi, None, Some(&out), false, migration).unwrap();
+    generate(&abi, None, Some(&out), true, migration).unwrap();
+
+    // A changed ABI leaves the written types behind
+    fs::write(&abi, TRANSFER.replace("Transfer", "Approval")).unwrap();
+    assert!(generate(&abi, None, Some(&out), true, migration).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn pool_events_get_the_indexed_derive() {
+    let pool = read_abi(Path::new("abi/usdc_weth.abi")).unwrap();
+    let (_, source) = bindings(&pool).unwrap();
+
+    assert_eq!(
+        source
+            .matches("#[derive(eth_logs_derive::IndexedEvent)]")
+            .count(),
+        4
+    );
+    assert_eq!(
+        source
+            .matches("#[indexed_event(column = \"receiver\")]")
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn committed_types_are_up_to_date() {
+    generate(Path::new("abi"), None, None, true, migration).unwrap();
+}
+
+#[test]
+fn pool_migration_follows_the_indexed_tables() {
+    let pool = read_abi(Path::new("abi/usdc_weth.abi")).unwrap();
+    let sql = migration(&pool).unwrap();
+
+    assert!(sql.contains("CREATE TABLE IF NOT EXISTS swap_logs ("));
+    assert!(sql.contains("receiver_id INT NOT NULL REFERENCES addresses (id),"));
+    assert!(sql.contains(
+        "CREATE INDEX IF NOT EXISTS burn_logs_tick_lower_idx ON burn_logs (tick_lower);"
+    ));
+    assert!(sql.contains("CREATE OR REPLACE VIEW flash_events AS"));
+    assert!(!sql.contains("usdc_weth_pool_"));
+}
@@ -0,0 +1,68 @@
+use eth_logs::indexed::{ColumnKind, IndexedEvent};
+use eth_logs::types::pool_types::usdc_weth_pool::{BurnFilter, SwapFilter};
+use ethers::types::{H160, U256};
+
+/// An event defined outside `eth_logs`, which the derive must name by `::eth_logs` paths.
+#[derive(eth_logs_derive::IndexedEvent)]
+#[allow(dead_code)]
+struct TransferFilter {
+    from: H160,
+    to: H160,
+    value: U256,
+}
+
+#[test]
+fn derive_works_outside_the_crate() {
+    let schema = TransferFilter::SCHEMA;
+
+    assert_eq!(schema.table, "transfer_logs");
+    assert_eq!(schema.address_columns().collect::<Vec<_>>(), ["from", "to"]);
+    assert_eq!(schema.columns[2].kind, ColumnKind::Uint256);
+}
+
+#[test]
+fn schema_follows_event_fields() {
+    let schema = SwapFilter::SCHEMA;
+
+    assert_eq!(schema.table, "swap_logs");
+    assert_eq!(schema.view(), "swap_events");
+
+    let names: Vec<&str> = schema.columns.iter().map(|c| c.name).collect();
+    assert_eq!(
+        names,
+        [
+            "sender",
+            "receiver",
+            "amount0",
+            "amount1",
+            "sqrt_price_x96",
+            "liquidity",
+            "tick"
+        ]
+    );
+    assert_eq!(schema.columns[2].kind, ColumnKind::Int256);
+    assert_eq!(schema.columns[5].kind, ColumnKind::Uint128);
+    assert_eq!(schema.columns[6].kind, ColumnKind::Int);
+    assert_eq!(
+        schema.address_columns().collect::<Vec<_>>(),
+        ["sender", "receiver"]
+    );
+}
+
+#[test]
+fn create_table_stores_address_ids() {
+    let sql = BurnFilter::SCHEMA.create_table(false);
+
+    assert!(sql.contains("CREATE TABLE IF NOT EXISTS burn_logs ("));
+    assert!(sql.contains("owner_id INT NOT NULL REFERENCES addresses (id),"));
+    assert!(sql.contains("tick_lower INT NOT NULL,"));
+    assert!(sql.contains("amount0 BYTEA NOT NULL,"));
+
+    let indexes = BurnFilter::SCHEMA.create_indexes();
+    assert!(indexes.contains("burn_logs_owner_idx ON burn_logs (owner_id);"));
+    assert!(indexes.contains("burn_logs_tick_upper_idx ON burn_logs (tick_upper);"));
+    assert!(!indexes.contains("(amount0)"));
+
+    let partitioned = BurnFilter::SCHEMA.create_table(true);
+    assert!(partitioned.contains("PARTITION BY RANGE (block_number)"));
+}
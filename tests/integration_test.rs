@@ -1,9 +1,25 @@
-use eth_logs::{database::DB, types::pool_types::usdc_weth_pool::*};
-use ethers::types::{Address, I256, U256};
-use testcontainers::{clients, core::WaitFor, images::postgres::Postgres};
+use eth_logs::{
+    config::{RetentionSettings, Settings},
+    database::{partition_name, DB},
+    retention,
+    storage::Storage,
+    types::pool_types::usdc_weth_pool::*,
+};
+use ethers::{
+    prelude::LogMeta,
+    types::{Address, H256, I256, U256, U64},
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use testcontainers::{
+    clients,
+    core::WaitFor,
+    images::{generic::GenericImage, postgres::Postgres},
+    RunnableImage,
+};
 use tokio::test;
 
 #[test]
+#[allow(clippy::unnecessary_cast)]
 async fn insert_events() {
     let docker = clients::Cli::default();
 
@@ -36,7 +52,7 @@ async fn insert_events() {
         }
     });
 
-    let db = DB { client };
+    let db = DB::from_client(client);
 
     //setup database and create table
     let table = db.create_table().await;
@@ -83,10 +99,10 @@ async fn insert_events() {
         paid_1: U256::MAX,
     };
 
-    let s_result = db.insert_swap_event(s_events).await;
-    let m_result = db.insert_mint_event(m_events).await;
-    let b_result = db.insert_burn_event(b_events).await;
-    let f_result = db.insert_flash_event(f_events).await;
+    let s_result = db.insert_swap_event(None, None, s_events).await;
+    let m_result = db.insert_mint_event(None, None, m_events).await;
+    let b_result = db.insert_burn_event(None, None, b_events).await;
+    let f_result = db.insert_flash_event(None, None, f_events).await;
 
     // check insertions of events
     assert!(s_result.is_ok());
@@ -97,44 +113,44 @@ async fn insert_events() {
     // query the database
     let s_query = db
         .client
-        .query("SELECT * FROM swap_logs", &[])
+        .query("SELECT * FROM swap_events", &[])
         .await
         .unwrap();
 
     let b_query = db
         .client
-        .query("SELECT * FROM burn_logs", &[])
+        .query("SELECT * FROM burn_events", &[])
         .await
         .unwrap();
 
     let m_query = db
         .client
-        .query("SELECT * FROM mint_logs", &[])
+        .query("SELECT * FROM mint_events", &[])
         .await
         .unwrap();
 
     let f_query = db
         .client
-        .query("SELECT * FROM flash_logs", &[])
+        .query("SELECT * FROM flash_events", &[])
         .await
         .unwrap();
 
     //gather events into vec
     let swap_events: Vec<SwapFilter> = s_query
         .into_iter()
-        .map(|row| SwapFilter::from(row))
+        .map(|row| SwapFilter::try_from(&row).unwrap())
         .collect();
     let burn_events: Vec<BurnFilter> = b_query
         .into_iter()
-        .map(|row| BurnFilter::from(row))
+        .map(|row| BurnFilter::try_from(&row).unwrap())
         .collect();
     let mint_events: Vec<MintFilter> = m_query
         .into_iter()
-        .map(|row| MintFilter::from(row))
+        .map(|row| MintFilter::try_from(&row).unwrap())
         .collect();
     let flash_events: Vec<FlashFilter> = f_query
         .into_iter()
-        .map(|row| FlashFilter::from(row))
+        .map(|row| FlashFilter::try_from(&row).unwrap())
         .collect();
 
     let se = swap_events.first().unwrap();
@@ -180,3 +196,273 @@ async fn insert_events() {
     assert_eq!(U256::MAX, fe.paid_0);
     assert_eq!(U256::MAX, fe.paid_1);
 }
+
+#[test]
+async fn connects_over_tls() {
+    let docker = clients::Cli::default();
+
+    // The Debian based image ships a self-signed certificate
+    let image = GenericImage::new("postgres", "15")
+        .with_env_var("POSTGRES_HOST_AUTH_METHOD", "trust")
+        .with_wait_for(WaitFor::message_on_stderr(
+            "database system is ready to accept connections",
+        ));
+    let args = [
+        "postgres",
+        "-c",
+        "ssl=on",
+        "-c",
+        "ssl_cert_file=/etc/ssl/certs/ssl-cert-snakeoil.pem",
+        "-c",
+        "ssl_key_file=/etc/ssl/private/ssl-cert-snakeoil.key",
+    ]
+    .map(String::from)
+    .to_vec();
+
+    let pg_container = docker.run(RunnableImage::from((image, args)));
+    let pg_port = pg_container.get_host_port_ipv4(5432);
+
+    let settings = Settings::from_yaml(&format!(
+        "
+rpc:
+  url: ws://localhost:8545
+database:
+  username: postgres
+  name: postgres
+  port: {pg_port}
+  tls:
+    mode: require
+"
+    ))
+    .unwrap();
+
+    let db = DB::connect_with(&settings.database).await.unwrap();
+
+    let row = db
+        .client
+        .query_one(
+            "SELECT ssl FROM pg_stat_ssl WHERE pid = pg_backend_pid()",
+            &[],
+        )
+        .await
+        .unwrap();
+    assert!(row.get::<_, bool>(0));
+}
+
+#[test]
+async fn creates_partitions() {
+    let docker = clients::Cli::default();
+    let pg_container = docker.run(Postgres::default());
+    let pg_port = pg_container.get_host_port_ipv4(5432);
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user("postgres")
+        .password("postgres")
+        .host("localhost")
+        .port(pg_port)
+        .dbname("postgres")
+        .connect(tokio_postgres::NoTls)
+        .await
+        .unwrap();
+    tokio::spawn(connection);
+
+    let db = DB::from_client(client);
+    db.create_partitioned_table(1000).await.unwrap();
+    db.create_table().await.unwrap();
+    db.insert_block(18_000_123, H256::repeat_byte(0xaa), None)
+        .await
+        .unwrap();
+
+    let exists: bool = db
+        .client
+        .query_one(
+            "SELECT to_regclass($1) IS NOT NULL",
+            &[&partition_name("swap_logs", 18_000_000)],
+        )
+        .await
+        .unwrap()
+        .get(0);
+    assert!(exists);
+
+    // Bulk copies create the partitions of their blocks
+    let swaps = vec![
+        (1, 18_000_123, SwapFilter::default()),
+        (2, 18_001_500, SwapFilter::default()),
+    ];
+    assert_eq!(2, db.copy_events(&swaps).await.unwrap());
+    let copied: i64 = db
+        .client
+        .query_one(
+            &format!(
+                "SELECT COUNT(*) FROM {}",
+                partition_name("swap_logs", 18_001_000)
+            ),
+            &[],
+        )
+        .await
+        .unwrap()
+        .get(0);
+    assert_eq!(1, copied);
+
+    // Tables created without partitions are not silently kept
+    db.client
+        .batch_execute(
+            "
+            DROP SCHEMA public CASCADE;
+            CREATE SCHEMA public;
+            ",
+        )
+        .await
+        .unwrap();
+    db.create_table().await.unwrap();
+    assert!(db.create_partitioned_table(1000).await.is_err());
+}
+
+#[test]
+async fn prunes_on_block_time() {
+    let docker = clients::Cli::default();
+    let pg_container = docker.run(Postgres::default());
+    let pg_port = pg_container.get_host_port_ipv4(5432);
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user("postgres")
+        .password("postgres")
+        .host("localhost")
+        .port(pg_port)
+        .dbname("postgres")
+        .connect(tokio_postgres::NoTls)
+        .await
+        .unwrap();
+    tokio::spawn(connection);
+
+    let db = DB::from_client(client);
+    db.create_table().await.unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    for (block_number, age_days) in [(1u64, 10u64), (2, 1)] {
+        db.insert_block(
+            block_number,
+            H256::repeat_byte(block_number as u8),
+            Some(now - age_days * 24 * 60 * 60),
+        )
+        .await
+        .unwrap();
+
+        let meta = LogMeta {
+            address: Address::zero(),
+            block_number: U64::from(block_number),
+            block_hash: H256::repeat_byte(block_number as u8),
+            transaction_hash: H256::zero(),
+            transaction_index: U64::zero(),
+            log_index: U256::zero(),
+        };
+        db.insert_event(&meta, &USDC_WETH_POOLEvents::SwapFilter(Default::default()))
+            .await
+            .unwrap();
+        db.commit().await.unwrap();
+    }
+
+    let settings = RetentionSettings {
+        interval_secs: 3600,
+        batch_size: 1,
+        tables: [
+            ("swap_logs".to_string(), 5),
+            ("rollups_hour".to_string(), 5),
+        ]
+        .into_iter()
+        .collect(),
+    };
+    assert_eq!(retention::prune(&db, &settings).await.unwrap(), 2);
+
+    for table in ["swap_events", "rollups_hour", "rollup_traders_hour"] {
+        let count: i64 = db
+            .client
+            .query_one(&format!("SELECT COUNT(*) FROM {table}"), &[])
+            .await
+            .unwrap()
+            .get(0);
+        assert_eq!(count, 1, "{table}");
+    }
+}
+
+/// Sums the swaps, traders and token0 volume of every hourly rollup.
+async fn rollup_totals(db: &DB) -> (i64, i64, String) {
+    let row = db
+        .client
+        .query_one(
+            "
+            SELECT COALESCE(SUM(swaps), 0)::BIGINT, COALESCE(SUM(traders), 0)::BIGINT,
+                   COALESCE(SUM(volume0), 0)::TEXT
+            FROM rollups_hour
+            ",
+            &[],
+        )
+        .await
+        .unwrap();
+
+    (row.get(0), row.get(1), row.get(2))
+}
+
+#[test]
+async fn rollups_count_traders_and_reverse() {
+    let docker = clients::Cli::default();
+    let pg_container = docker.run(Postgres::default());
+    let pg_port = pg_container.get_host_port_ipv4(5432);
+
+    let (client, connection) = tokio_postgres::Config::new()
+        .user("postgres")
+        .password("postgres")
+        .host("localhost")
+        .port(pg_port)
+        .dbname("postgres")
+        .connect(tokio_postgres::NoTls)
+        .await
+        .unwrap();
+    tokio::spawn(connection);
+
+    let db = DB::from_client(client);
+    db.create_table().await.unwrap();
+
+    let swap = |recipient: u8| {
+        USDC_WETH_POOLEvents::SwapFilter(SwapFilter {
+            recipient: Address::repeat_byte(recipient),
+            amount_0: I256::from(1_000_000),
+            amount_1: I256::from(-1),
+            ..Default::default()
+        })
+    };
+
+    db.insert_block(1, H256::repeat_byte(1), Some(1_700_000_000))
+        .await
+        .unwrap();
+    for (log_index, recipient) in [(0u64, 0x11), (1, 0x11), (2, 0x22)] {
+        let meta = LogMeta {
+            address: Address::repeat_byte(0xaa),
+            block_number: U64::from(1),
+            block_hash: H256::repeat_byte(1),
+            transaction_hash: H256::zero(),
+            transaction_index: U64::zero(),
+            log_index: U256::from(log_index),
+        };
+        db.insert_event(&meta, &swap(recipient)).await.unwrap();
+    }
+    db.commit().await.unwrap();
+
+    // Two swaps of the same recipient count as a single trader
+    assert_eq!(rollup_totals(&db).await, (3, 2, "3000000".to_string()));
+
+    // Removing the block subtracts exactly what was added
+    db.rollback_to_block(0).await.unwrap();
+    assert_eq!(rollup_totals(&db).await, (0, 0, "0".to_string()));
+
+    let traders: i64 = db
+        .client
+        .query_one("SELECT COUNT(*) FROM rollup_traders_hour", &[])
+        .await
+        .unwrap()
+        .get(0);
+    assert_eq!(traders, 0);
+}
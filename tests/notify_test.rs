@@ -0,0 +1,30 @@
+use eth_logs::{notify::Notification, types::pool_types::usdc_weth_pool::*};
+use ethers::{
+    prelude::LogMeta,
+    types::{Address, H256, U256, U64},
+};
+
+#[test]
+fn notification_payload_is_compact_json() {
+    let meta = LogMeta {
+        address: Address::repeat_byte(0x11),
+        block_number: U64::from(42),
+        block_hash: H256::zero(),
+        transaction_hash: H256::zero(),
+        transaction_index: U64::zero(),
+        log_index: U256::from(3),
+    };
+
+    let event = USDC_WETH_POOLEvents::MintFilter(MintFilter::default());
+    let notification = Notification::new(&meta, &event, 7).unwrap();
+
+    assert_eq!("ethlog_mint", notification.channel());
+    let payload = serde_json::to_string(&notification).unwrap();
+    assert!(payload.starts_with(
+        r#"{"id":7,"pool":"0x1111111111111111111111111111111111111111","block_number":42,"#
+    ));
+    assert!(payload.contains(r#""log_index":3,"event":{"type":"mint","#));
+
+    let decoded: Notification = serde_json::from_str(&payload).unwrap();
+    assert_eq!(notification, decoded);
+}
@@ -0,0 +1,17 @@
+use eth_logs::database::{bytea_literal, partition_name};
+
+#[test]
+fn partition_names_sort_by_block() {
+    assert_eq!(partition_name("swap_logs", 0), "swap_logs_p000000000000");
+    assert_eq!(
+        partition_name("raw_logs", 18_000_000),
+        "raw_logs_p000018000000"
+    );
+    assert!(partition_name("swap_logs", 900_000) < partition_name("swap_logs", 18_000_000));
+}
+
+#[test]
+fn bytea_literals_match_block_number_columns() {
+    assert_eq!(bytea_literal(0), "'\\x0000000000000000'::bytea");
+    assert_eq!(bytea_literal(18_000_000), "'\\x000000000112a880'::bytea");
+}
@@ -0,0 +1,83 @@
+use eth_logs::{
+    export::{EventKind, LogRecord},
+    query::{offset_bytes, Cursor, EventQuery, Indexed, Page},
+};
+use ethers::types::{H160, I256};
+
+#[test]
+fn sql_combines_filters_in_order() {
+    let query = EventQuery {
+        pool: Some(H160::repeat_byte(0x11)),
+        from_block: Some(10),
+        address: Some(H160::repeat_byte(0x22)),
+        min_amount0: Some(I256::from(-5)),
+        tick_lower: Some(-100),
+        after: Some(Cursor(7)),
+        limit: 2,
+        ..Default::default()
+    };
+
+    let (sql, params) = query.sql(EventKind::Swap).unwrap();
+    let sql = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    assert!(sql.contains(
+        "WHERE l.address = $1 AND l.block_number >= $2 \
+         AND (e.sender_address = $3 OR e.receiver_address = $3) \
+         AND set_byte(e.amount0, 0, get_byte(e.amount0, 0) # 128) >= $4 \
+         AND e.tick >= $5 AND e.id > $6 ORDER BY e.id LIMIT 3"
+    ));
+    assert_eq!(params.len(), 6);
+
+    let (sql, params) = EventQuery::default().sql(EventKind::Mint).unwrap();
+    assert!(!sql.contains("WHERE"));
+    assert!(params.is_empty());
+
+    let ticks = EventQuery {
+        tick_upper: Some(5),
+        ..Default::default()
+    };
+    assert!(ticks.sql(EventKind::Flash).is_err());
+    assert!(ticks
+        .sql(EventKind::Burn)
+        .unwrap()
+        .0
+        .contains("e.tick_upper <= $1"));
+}
+
+#[test]
+fn pages_continue_after_the_last_item() {
+    let item = |id| Indexed {
+        id,
+        log: LogRecord::default(),
+        event: (),
+    };
+
+    let page = Page::new((1..=3).map(item).collect(), 2);
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.next, Some(Cursor(2)));
+
+    let page = Page::new((1..=2).map(item).collect(), 2);
+    assert_eq!(page.next, None);
+
+    let empty = EventQuery {
+        limit: 0,
+        ..Default::default()
+    };
+    assert!(empty.sql(EventKind::Swap).is_err());
+}
+
+#[test]
+fn offset_bytes_sort_like_signed_amounts() {
+    let amounts = [
+        I256::MIN,
+        I256::from(-5),
+        I256::from(-1),
+        I256::zero(),
+        I256::from(1),
+        I256::MAX,
+    ];
+
+    for pair in amounts.windows(2) {
+        assert!(offset_bytes(pair[0]) < offset_bytes(pair[1]));
+    }
+}
@@ -0,0 +1,62 @@
+use eth_logs::{
+    config::RetentionSettings,
+    retention::{prune_statement, validate},
+};
+use std::collections::HashMap;
+
+fn settings(tables: &[(&str, u64)]) -> RetentionSettings {
+    RetentionSettings {
+        interval_secs: 3600,
+        batch_size: 100,
+        tables: tables
+            .iter()
+            .map(|(table, days)| (table.to_string(), *days))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+#[test]
+fn validate_accepts_event_tables() {
+    assert!(validate(&settings(&[("swap_logs", 90), ("mint_logs", 30)])).is_ok());
+}
+
+#[test]
+fn validate_accepts_log_and_rollup_tables() {
+    assert!(validate(&settings(&[
+        ("raw_logs", 7),
+        ("dead_letters", 30),
+        ("rollups_minute", 2)
+    ]))
+    .is_ok());
+
+    // Log rows are pruned with their events
+    assert!(validate(&settings(&[("ethereum_logs", 90)])).is_err());
+}
+
+#[test]
+fn rollups_are_pruned_with_their_traders() {
+    let statement = prune_statement("rollups_hour").unwrap();
+
+    assert!(statement.contains("date_trunc('hour'"));
+    assert!(statement.contains("DELETE FROM rollup_traders_hour"));
+    assert!(prune_statement("rollups_week").is_none());
+}
+
+#[test]
+fn validate_rejects_unknown_tables() {
+    assert!(validate(&settings(&[("blocks", 90)])).is_err());
+    assert!(validate(&settings(&[("swap_logs; DROP TABLE blocks", 90)])).is_err());
+}
+
+#[test]
+fn validate_rejects_invalid_settings() {
+    let mut zero_interval = settings(&[("swap_logs", 90)]);
+    zero_interval.interval_secs = 0;
+    assert!(validate(&zero_interval).is_err());
+
+    let mut zero_batch = settings(&[("swap_logs", 90)]);
+    zero_batch.batch_size = 0;
+    assert!(validate(&zero_batch).is_err());
+
+    assert!(validate(&settings(&[("swap_logs", u64::MAX)])).is_err());
+}
@@ -0,0 +1,51 @@
+use eth_logs::rollup::{contribution, Contribution};
+use eth_logs::types::pool_types::usdc_weth_pool::*;
+use ethers::types::{Address, I256, U256};
+
+#[test]
+fn swap_contributes_volume_fees_and_trader() {
+    let swap = USDC_WETH_POOLEvents::SwapFilter(SwapFilter {
+        sender: Address::repeat_byte(0x11),
+        recipient: Address::repeat_byte(0x22),
+        amount_0: I256::from(2_000_000),
+        amount_1: I256::from(-1_000),
+        sqrt_price_x96: U256::one(),
+        liquidity: 1,
+        tick: 0,
+    });
+
+    assert_eq!(
+        Some(Contribution {
+            swaps: 1,
+            volume0: U256::from(2_000_000),
+            volume1: U256::from(1_000),
+            fees0: U256::from(1_000),
+            fees1: U256::zero(),
+            trader: Some(Address::repeat_byte(0x22)),
+            ..Default::default()
+        }),
+        contribution(&swap)
+    );
+}
+
+#[test]
+fn flash_contributes_paid_fees() {
+    let flash = USDC_WETH_POOLEvents::FlashFilter(FlashFilter {
+        sender: Address::repeat_byte(0x11),
+        recipient: Address::repeat_byte(0x22),
+        amount_0: U256::from(100),
+        amount_1: U256::from(200),
+        paid_0: U256::from(3),
+        paid_1: U256::from(4),
+    });
+
+    assert_eq!(
+        Some(Contribution {
+            flashes: 1,
+            fees0: U256::from(3),
+            fees1: U256::from(4),
+            ..Default::default()
+        }),
+        contribution(&flash)
+    );
+}
@@ -0,0 +1,572 @@
+use async_trait::async_trait;
+use eth_logs::{
+    coverage::{self, Interval},
+    decode::{index_events, index_logs},
+    error::IndexerError,
+    indexed::{Column, IndexedEvent, SCHEMAS},
+    ingest::BlockTimes,
+    storage::{
+        memory::MemoryStorage, parquet::ParquetSink, sqlite::SqliteStorage, tee::Tee, Storage,
+    },
+    types::{pool_types::usdc_weth_pool::*, Wu128, WI256, WU256},
+};
+use ethers::{
+    abi::{encode, RawLog, Token},
+    contract::EthEvent,
+    prelude::LogMeta,
+    types::{Address, Log, H256, I256, U256, U64},
+};
+use futures::stream;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use std::fs::File;
+use tokio::test;
+
+/// Builds log metadata for a log at the given block and log index.
+fn meta(block: u64, log_index: u64) -> LogMeta {
+    LogMeta {
+        address: Default::default(),
+        block_number: U64::from(block),
+        block_hash: H256::from_low_u64_be(block),
+        transaction_hash: H256::from_low_u64_be(log_index),
+        transaction_index: U64::zero(),
+        log_index: U256::from(log_index),
+    }
+}
+
+fn mint() -> USDC_WETH_POOLEvents {
+    USDC_WETH_POOLEvents::MintFilter(MintFilter::default())
+}
+
+#[test]
+async fn index_events_commits_blocks() {
+    let storage = MemoryStorage::new();
+
+    let events = vec![
+        (mint(), meta(1, 0)),
+        (mint(), meta(1, 1)),
+        (mint(), meta(2, 2)),
+    ];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+
+    index_events(stream, &storage).await.unwrap();
+
+    assert_eq!(3, storage.events().len());
+    assert_eq!(
+        vec![(1, H256::from_low_u64_be(1)), (2, H256::from_low_u64_be(2))],
+        storage.blocks()
+    );
+    assert_eq!(Some(2), storage.checkpoint().await.unwrap());
+}
+
+#[test]
+async fn index_events_rolls_back_replayed_blocks() {
+    let storage = MemoryStorage::new();
+
+    let events = vec![
+        (mint(), meta(1, 0)),
+        (mint(), meta(2, 1)),
+        (mint(), meta(3, 2)),
+    ];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    // Block 2 is replaced by a block with another hash
+    let reorged = LogMeta {
+        block_hash: H256::repeat_byte(0xaa),
+        ..meta(2, 5)
+    };
+    let events = vec![(mint(), reorged)];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    let log_indexes: Vec<u64> = storage
+        .events()
+        .iter()
+        .map(|(meta, _)| meta.log_index.as_u64())
+        .collect();
+
+    assert_eq!(vec![0, 5], log_indexes);
+    assert_eq!(Some(2), storage.checkpoint().await.unwrap());
+}
+
+#[test]
+async fn index_events_skips_blocks_replayed_unchanged() {
+    let storage = MemoryStorage::new();
+
+    let events = vec![(mint(), meta(1, 0)), (mint(), meta(2, 1))];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    // Block 1 is delivered again with the same hash
+    let events = vec![(mint(), meta(1, 0)), (mint(), meta(3, 2))];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    assert_eq!(3, storage.events().len());
+    assert_eq!(Some(3), storage.checkpoint().await.unwrap());
+}
+
+#[test]
+async fn index_events_returns_stream_errors() {
+    let storage = MemoryStorage::new();
+
+    let events = vec![Ok((mint(), meta(1, 0))), Err(eyre::eyre!("disconnected"))];
+    let err = index_events(stream::iter(events), &storage)
+        .await
+        .unwrap_err();
+
+    assert_eq!("disconnected", err.to_string());
+}
+
+#[test]
+async fn sqlite_event_tables_follow_their_schema() {
+    let storage = SqliteStorage::open_in_memory().unwrap();
+    storage.create_table().await.unwrap();
+
+    let conn = storage.conn.lock().unwrap();
+    for schema in SCHEMAS {
+        let mut statement = conn
+            .prepare("SELECT name FROM pragma_table_info(?1) ORDER BY cid")
+            .unwrap();
+        let columns: Vec<String> = statement
+            .query_map([schema.table], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let mut expected = vec!["id".to_string(), "ethereum_log_id".to_string()];
+        expected.extend(schema.columns.iter().map(Column::sqlite_name));
+        expected.push("timestamp".to_string());
+        assert_eq!(expected, columns);
+    }
+    assert_eq!(
+        "receiver_address",
+        SwapFilter::SCHEMA.columns[1].sqlite_name()
+    );
+}
+
+#[test]
+async fn sqlite_storage_round_trip() {
+    let storage = SqliteStorage::open_in_memory().unwrap();
+    storage.create_table().await.unwrap();
+
+    let swap = SwapFilter {
+        amount_0: I256::from(-5),
+        amount_1: I256::from(7),
+        sqrt_price_x96: U256::MAX,
+        liquidity: u128::MAX,
+        tick: -10,
+        ..Default::default()
+    };
+    let events = vec![
+        (USDC_WETH_POOLEvents::SwapFilter(swap.clone()), meta(1, 0)),
+        (mint(), meta(2, 1)),
+    ];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    assert_eq!(Some(2), storage.checkpoint().await.unwrap());
+
+    {
+        let conn = storage.conn.lock().unwrap();
+        let stored = conn
+            .query_row(
+                "SELECT amount0, amount1, sqrt_price_x96, liquidity, tick FROM swap_logs",
+                [],
+                |row| {
+                    Ok((
+                        *row.get::<_, WI256>(0)?,
+                        *row.get::<_, WI256>(1)?,
+                        *row.get::<_, WU256>(2)?,
+                        *row.get::<_, Wu128>(3)?,
+                        row.get::<_, i32>(4)?,
+                    ))
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            (
+                swap.amount_0,
+                swap.amount_1,
+                swap.sqrt_price_x96,
+                swap.liquidity,
+                swap.tick
+            ),
+            stored
+        );
+    }
+
+    // Rolling back removes the mint of block 2 through the cascading foreign key
+    storage.rollback_to_block(1).await.unwrap();
+    assert_eq!(Some(1), storage.checkpoint().await.unwrap());
+
+    let conn = storage.conn.lock().unwrap();
+    let mints: i64 = conn
+        .query_row("SELECT COUNT(*) FROM mint_logs", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(0, mints);
+}
+
+#[test]
+async fn parquet_sink_rolls_files_by_block_range() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_parquet_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let storage = Tee::new(
+        MemoryStorage::new(),
+        ParquetSink::open(&dir, 10, 0).unwrap(),
+    );
+
+    let events = vec![
+        (mint(), meta(1, 0)),
+        (mint(), meta(5, 1)),
+        (mint(), meta(12, 2)),
+    ];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    // Blocks 1 to 5 are flushed as soon as block 12 leaves their range
+    let file = File::open(dir.join("mints_0000000001_0000000005.parquet")).unwrap();
+    let rows: usize = ParquetRecordBatchReaderBuilder::try_new(file)
+        .unwrap()
+        .build()
+        .unwrap()
+        .map(|batch| batch.unwrap().num_rows())
+        .sum();
+    assert_eq!(2, rows);
+
+    // Rolling back into a flushed range is refused
+    assert!(storage.secondary.rollback_to_block(3).await.is_err());
+
+    storage.close().await.unwrap();
+    assert!(dir.join("mints_0000000012_0000000012.parquet").exists());
+
+    // A reopened sink resumes from the last flushed block
+    let reopened = ParquetSink::open(&dir, 10, 0).unwrap();
+    assert_eq!(Some(12), reopened.checkpoint().await.unwrap());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+async fn tee_replays_the_blocks_a_sink_lost() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_parquet_replay_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let storage = Tee::new(
+        MemoryStorage::new(),
+        ParquetSink::open(&dir, 10, 0).unwrap(),
+    );
+    let events = vec![(mint(), meta(1, 0)), (mint(), meta(12, 1))];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    // The sink loses block 12, which it never flushed, without being closed
+    let storage = Tee::new(storage.primary, ParquetSink::open(&dir, 10, 0).unwrap());
+    assert_eq!(Some(1), storage.checkpoint().await.unwrap());
+
+    let events = vec![(mint(), meta(12, 1)), (mint(), meta(13, 2))];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+    storage.close().await.unwrap();
+
+    // Block 12 is only written again to the sink
+    assert_eq!(3, storage.primary.events().len());
+    let file = File::open(dir.join("mints_0000000012_0000000013.parquet")).unwrap();
+    let rows: usize = ParquetRecordBatchReaderBuilder::try_new(file)
+        .unwrap()
+        .build()
+        .unwrap()
+        .map(|batch| batch.unwrap().num_rows())
+        .sum();
+    assert_eq!(2, rows);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+async fn parquet_sink_keeps_reorg_depth_in_memory() {
+    let dir = std::env::temp_dir().join(format!("eth_logs_parquet_depth_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let storage = Tee::new(
+        MemoryStorage::new(),
+        ParquetSink::open(&dir, 10, 5).unwrap(),
+    );
+
+    let events = vec![
+        (mint(), meta(8, 0)),
+        (mint(), meta(9, 1)),
+        (mint(), meta(12, 2)),
+    ];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    // Block 9 is within 5 blocks of block 12, so its range is still in memory
+    assert!(!dir.join("mints_0000000008_0000000009.parquet").exists());
+
+    // Block 9 is reorged, and its range is written once block 20 settles it
+    let reorged = LogMeta {
+        block_hash: H256::repeat_byte(0xaa),
+        ..meta(9, 3)
+    };
+    let events = vec![(mint(), reorged), (mint(), meta(20, 4))];
+    let stream = stream::iter(events.into_iter().map(Ok::<_, eyre::Report>));
+    index_events(stream, &storage).await.unwrap();
+
+    let file = File::open(dir.join("mints_0000000008_0000000009.parquet")).unwrap();
+    let rows: usize = ParquetRecordBatchReaderBuilder::try_new(file)
+        .unwrap()
+        .build()
+        .unwrap()
+        .map(|batch| batch.unwrap().num_rows())
+        .sum();
+    assert_eq!(2, rows);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+async fn index_events_records_coverage_between_runs() {
+    let storage = MemoryStorage::new();
+
+    let first = vec![(mint(), meta(1, 0)), (mint(), meta(3, 1))];
+    index_events(
+        stream::iter(first.into_iter().map(Ok::<_, eyre::Report>)),
+        &storage,
+    )
+    .await
+    .unwrap();
+
+    let second = vec![(mint(), meta(10, 2)), (mint(), meta(12, 3))];
+    index_events(
+        stream::iter(second.into_iter().map(Ok::<_, eyre::Report>)),
+        &storage,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        vec![
+            Interval { start: 1, end: 3 },
+            Interval { start: 10, end: 12 }
+        ],
+        storage.coverage()
+    );
+    assert_eq!(
+        vec![Interval { start: 4, end: 9 }],
+        coverage::gaps(&storage.coverage())
+    );
+
+    // A batch covers its blocks without logs once they are committed
+    storage.cover_blocks(13, 20).await.unwrap();
+    assert_eq!(Interval { start: 10, end: 20 }, storage.coverage()[1]);
+
+    // A backfill of the gap merges everything into one interval
+    let mut intervals = storage.coverage();
+    coverage::insert(&mut intervals, Interval { start: 4, end: 9 });
+    assert_eq!(vec![Interval { start: 1, end: 20 }], intervals);
+}
+
+/// Builds a mined log at the given block and log index.
+fn log(block: u64, log_index: u64, topics: Vec<H256>, data: Vec<u8>) -> Log {
+    let meta = meta(block, log_index);
+    Log {
+        address: meta.address,
+        topics,
+        data: data.into(),
+        block_hash: Some(meta.block_hash),
+        block_number: Some(meta.block_number),
+        transaction_hash: Some(meta.transaction_hash),
+        transaction_index: Some(meta.transaction_index),
+        log_index: Some(meta.log_index),
+        ..Default::default()
+    }
+}
+
+#[test]
+async fn index_logs_keeps_raw_logs_and_dead_letters() {
+    let storage = MemoryStorage::new();
+
+    let sender = Address::repeat_byte(0x11);
+    let recipient = Address::repeat_byte(0x22);
+    let flash = log(
+        1,
+        0,
+        vec![FlashFilter::signature(), sender.into(), recipient.into()],
+        encode(&[
+            Token::Uint(U256::from(1)),
+            Token::Uint(U256::from(2)),
+            Token::Uint(U256::from(3)),
+            Token::Uint(U256::from(4)),
+        ]),
+    );
+    let unknown = log(1, 1, vec![H256::repeat_byte(0xff)], vec![]);
+
+    let stream = stream::iter(
+        vec![flash.clone(), unknown.clone()]
+            .into_iter()
+            .map(Ok::<_, eyre::Report>),
+    );
+    index_logs(stream, &storage, true, &()).await.unwrap();
+
+    let events = storage.events();
+    assert_eq!(1, events.len());
+    assert_eq!(
+        USDC_WETH_POOLEvents::FlashFilter(FlashFilter {
+            sender,
+            recipient,
+            amount_0: U256::from(1),
+            amount_1: U256::from(2),
+            paid_0: U256::from(3),
+            paid_1: U256::from(4),
+        }),
+        events[0].1
+    );
+
+    let raw_logs: Vec<RawLog> = storage.raw_logs().into_iter().map(|(_, raw)| raw).collect();
+    assert_eq!(
+        vec![RawLog::from(flash), RawLog::from(unknown.clone())],
+        raw_logs
+    );
+
+    let dead_letters = storage.dead_letters();
+    assert_eq!(1, dead_letters.len());
+    assert_eq!(Some(RawLog::from(unknown)), dead_letters[0].1);
+    assert!(dead_letters[0].2.starts_with("failed to decode log"));
+}
+
+#[test]
+async fn sqlite_lists_and_retries_dead_letters() {
+    let storage = SqliteStorage::open_in_memory().unwrap();
+    storage.create_table().await.unwrap();
+
+    let flash = RawLog {
+        topics: vec![
+            FlashFilter::signature(),
+            Address::repeat_byte(0x11).into(),
+            Address::repeat_byte(0x22).into(),
+        ],
+        data: encode(&vec![Token::Uint(U256::from(1)); 4]),
+    };
+    let unknown = RawLog {
+        topics: vec![H256::repeat_byte(0xff)],
+        data: vec![],
+    };
+    storage
+        .insert_dead_letter(&meta(1, 0), Some(&flash), "connection reset")
+        .await
+        .unwrap();
+    storage
+        .insert_dead_letter(&meta(1, 1), Some(&unknown), "failed to decode log")
+        .await
+        .unwrap();
+
+    let letters = storage.dead_letters(10).await.unwrap();
+    assert_eq!(2, letters.len());
+    assert_eq!(Some(flash), letters[0].log);
+    assert_eq!(meta(1, 0), letters[0].meta);
+
+    // The flash is written, the unknown log fails again
+    assert_eq!((1, 1), storage.retry_dead_letters(None).await.unwrap());
+
+    let letters = storage.dead_letters(10).await.unwrap();
+    assert_eq!(1, letters.len());
+    assert_eq!(1, letters[0].retries);
+    let flashes: i64 = storage
+        .conn
+        .lock()
+        .unwrap()
+        .query_row("SELECT COUNT(*) FROM flash_logs", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(1, flashes);
+}
+
+#[test]
+async fn sqlite_rejects_a_block_hash_of_the_wrong_length() {
+    let storage = SqliteStorage::open_in_memory().unwrap();
+    storage.create_table().await.unwrap();
+
+    storage
+        .conn
+        .lock()
+        .unwrap()
+        .execute_batch("INSERT INTO blocks (number, hash) VALUES (7, x'abcd')")
+        .unwrap();
+
+    let error = storage.block_hash(7).await.unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<IndexerError>(),
+        Some(IndexerError::InvalidValue {
+            expected: 32,
+            actual: 2,
+            ..
+        })
+    ));
+}
+
+/// Every block was mined at the same time.
+struct FixedTimes(u64);
+
+#[async_trait]
+impl BlockTimes for FixedTimes {
+    async fn block_time(&self, _block_hash: H256) -> eyre::Result<Option<u64>> {
+        Ok(Some(self.0))
+    }
+}
+
+#[test]
+async fn index_logs_stores_block_times() {
+    let storage = SqliteStorage::open_in_memory().unwrap();
+    storage.create_table().await.unwrap();
+
+    let flash = log(
+        7,
+        0,
+        vec![FlashFilter::signature(), H256::zero(), H256::zero()],
+        encode(&vec![Token::Uint(U256::zero()); 4]),
+    );
+    let stream = stream::iter(vec![Ok::<_, eyre::Report>(flash)]);
+    index_logs(stream, &storage, false, &FixedTimes(1_700_000_000))
+        .await
+        .unwrap();
+
+    let conn = storage.conn.lock().unwrap();
+    let timestamp: i64 = conn
+        .query_row("SELECT timestamp FROM blocks WHERE number = 7", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(1_700_000_000, timestamp);
+}
+
+#[test]
+async fn sqlite_keeps_dead_letters() {
+    let storage = SqliteStorage::open_in_memory().unwrap();
+    storage.create_table().await.unwrap();
+
+    let unknown = log(3, 0, vec![H256::repeat_byte(0xff)], vec![0xab]);
+    let stream = stream::iter(vec![Ok::<_, eyre::Report>(unknown)]);
+    index_logs(stream, &storage, false, &()).await.unwrap();
+
+    {
+        let conn = storage.conn.lock().unwrap();
+        let (topic0, data, error): (Vec<u8>, Vec<u8>, String) = conn
+            .query_row("SELECT topic0, data, error FROM dead_letters", [], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })
+            .unwrap();
+        assert_eq!(vec![0xff; 32], topic0);
+        assert_eq!(vec![0xab], data);
+        assert!(error.starts_with("failed to decode log"));
+    }
+
+    storage.rollback_to_block(2).await.unwrap();
+    let count: i64 = storage
+        .conn
+        .lock()
+        .unwrap()
+        .query_row("SELECT COUNT(*) FROM dead_letters", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(0, count);
+}
@@ -0,0 +1,119 @@
+use eth_logs::{
+    export::{Cell, EventKind, LogRecord},
+    types::pool_types::usdc_weth_pool::*,
+    verify::{diff, ChainEvent, Report, StoredEvent, UndecodableLog},
+};
+use ethers::{
+    abi::RawLog,
+    prelude::LogMeta,
+    types::{Address, H256, U256, U64},
+};
+
+fn meta(block: u64, tx: u8, log_index: u64) -> LogMeta {
+    LogMeta {
+        address: Address::repeat_byte(0x11),
+        block_number: U64::from(block),
+        block_hash: H256::zero(),
+        transaction_hash: H256::repeat_byte(tx),
+        transaction_index: U64::zero(),
+        log_index: U256::from(log_index),
+    }
+}
+
+fn flash(paid: u64) -> USDC_WETH_POOLEvents {
+    USDC_WETH_POOLEvents::FlashFilter(FlashFilter {
+        amount_0: U256::from(100),
+        paid_0: U256::from(paid),
+        ..Default::default()
+    })
+}
+
+fn stored(id: i32, meta: &LogMeta, event: USDC_WETH_POOLEvents) -> StoredEvent {
+    StoredEvent {
+        kind: EventKind::Flash,
+        id,
+        log: LogRecord {
+            ethereum_log_id: Some(id),
+            ..LogRecord::from_meta(meta)
+        },
+        event,
+    }
+}
+
+#[test]
+fn diff_reports_missing_extra_and_mismatched_events() {
+    let chain = vec![
+        ChainEvent {
+            meta: meta(1, 0xaa, 0),
+            event: flash(1),
+        },
+        ChainEvent {
+            meta: meta(2, 0xbb, 3),
+            event: flash(2),
+        },
+    ];
+
+    let stored = vec![
+        // Written with amount0 in the paid0 column
+        stored(1, &meta(1, 0xaa, 0), flash(100)),
+        stored(2, &meta(3, 0xcc, 0), flash(3)),
+    ];
+
+    let report = diff(chain, stored);
+
+    assert_eq!(report.missing.len(), 1);
+    assert_eq!(report.missing[0].meta.block_number, U64::from(2));
+
+    assert_eq!(report.extra.len(), 1);
+    assert_eq!(report.extra[0].id, 2);
+
+    assert_eq!(report.mismatched.len(), 1);
+    let fields = &report.mismatched[0].fields;
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].column, "paid0");
+    assert_eq!(fields[0].stored, Cell::Text("100".into()));
+    assert_eq!(fields[0].chain, Cell::Text("1".into()));
+}
+
+#[test]
+fn diff_matches_rows_without_log_index_by_transaction() {
+    let chain = vec![
+        ChainEvent {
+            meta: meta(1, 0xaa, 4),
+            event: flash(1),
+        },
+        ChainEvent {
+            meta: meta(1, 0xaa, 7),
+            event: flash(2),
+        },
+    ];
+
+    let mut first = stored(1, &meta(1, 0xaa, 0), flash(1));
+    first.log.log_index = None;
+    let mut second = stored(2, &meta(1, 0xaa, 0), flash(2));
+    second.log.log_index = None;
+
+    let report = diff(chain, vec![first, second]);
+
+    assert!(report.is_clean());
+}
+
+#[test]
+fn undecodable_logs_are_reported() {
+    let report = Report {
+        undecodable: vec![UndecodableLog {
+            meta: meta(4, 0xdd, 1),
+            log: RawLog {
+                topics: vec![H256::repeat_byte(0xee)],
+                data: Vec::new(),
+            },
+            error: "unknown event".into(),
+        }],
+        ..Default::default()
+    };
+
+    assert!(!report.is_clean());
+    let text = report.to_string();
+    assert!(text.contains("undecodable block 4"));
+    assert!(text.ends_with("0 missing, 0 extra, 0 mismatched, 1 undecodable"));
+}